@@ -0,0 +1,62 @@
+use std::io::Write;
+
+use anyhow::Result;
+use clap::{arg, Arg, ArgAction, ArgMatches, Command};
+use log::LevelFilter;
+
+use crate::{default_filename, Input};
+
+/// Command line of the binary of a single day: the input file and the verbosity flags.
+///
+/// Days with options of their own add them to this command.
+pub fn command(day: u8, title: &'static str) -> Command {
+    Command::new(format!("day{day}"))
+        .version(env!("CARGO_PKG_VERSION"))
+        .about(format!("Day {day}: {title}"))
+        .arg(Arg::new("INPUT").help(format!(
+            "Puzzle input, or '-' for stdin [default: {}]",
+            default_filename(day)
+        )))
+        .args(verbosity_args())
+}
+
+/// `-v`, `-vv` and `-q` flags, setting how much diagnostic output is logged to stderr.
+pub fn verbosity_args() -> [Arg; 2] {
+    [
+        arg!(-v --verbose "Logs diagnostics to stderr, -vv for a detailed trace")
+            .action(ArgAction::Count)
+            .global(true),
+        arg!(-q --quiet "Logs nothing but errors")
+            .conflicts_with("verbose")
+            .global(true),
+    ]
+}
+
+/// Sets up logging to stderr at the level chosen with the [`verbosity_args`].
+///
+/// The `RUST_LOG` environment variable still takes precedence, for filtering by module.
+pub fn init_logging(matches: &ArgMatches) {
+    let level = if matches.get_flag("quiet") {
+        LevelFilter::Error
+    } else {
+        match matches.get_count("verbose") {
+            0 => LevelFilter::Warn,
+            1 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        }
+    };
+
+    env_logger::Builder::new()
+        .filter_level(level)
+        .parse_default_env()
+        .format(|buf, record| writeln!(buf, "{}", record.args()))
+        .init();
+}
+
+/// Opens the input named on the command line of the day, or the default file of the day.
+pub fn input(matches: &ArgMatches, day: u8) -> Result<Input> {
+    match matches.get_one::<String>("INPUT") {
+        Some(filename) => Input::open(filename),
+        None => Input::open(&default_filename(day)),
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+
+/// Answers a puzzle input is expected to give, as recorded in a file like
+///
+/// ```text
+/// # Example from the puzzle description
+/// part1 = 4361
+/// part2 = 467835
+/// ```
+///
+/// Either part may be left out when its answer is not known yet. A part that is expected
+/// to fail is recorded with the message of its error, like `part1 = error: No digits`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    /// Records the answer given on a `part = answer` line.
+    fn record(&mut self, line: &str, number: usize) -> Result<()> {
+        let (key, value) = line
+            .split_once('=')
+            .with_context(|| format!("Expected 'part = answer' on line {number}: {line:?}"))?;
+
+        let answer = Some(value.trim().to_string());
+        match key.trim() {
+            "part1" => self.part1 = answer,
+            "part2" => self.part2 = answer,
+            key => bail!("Unknown part {key:?} on line {number}"),
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Expected {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut expected = Expected::default();
+
+        for (number, line) in significant_lines(s) {
+            expected.record(line, number)?;
+        }
+
+        Ok(expected)
+    }
+}
+
+/// Prefix of a recorded answer standing for an error whose message contains the rest of it.
+const ERROR: &str = "error:";
+
+/// Whether the `actual` outcome of a part, either its answer or the message of its error,
+/// is the `expected` one recorded in an [`Expected`] or an [`AnswerSheet`].
+pub fn answer_matches(expected: &str, actual: &Result<String, String>) -> bool {
+    match (expected.strip_prefix(ERROR), actual) {
+        (Some(message), Err(error)) => error.contains(message.trim()),
+        (None, Ok(answer)) => answer == expected,
+        _ => false,
+    }
+}
+
+/// Answers recorded for several days, in sections headed by the day:
+///
+/// ```text
+/// [day1]
+/// part1 = 54338
+/// part2 = 53389
+///
+/// [day2]
+/// part1 = 2913
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct AnswerSheet {
+    days: BTreeMap<u8, Expected>,
+}
+
+impl AnswerSheet {
+    /// Answers recorded for the given day.
+    pub fn day(&self, day: u8) -> Option<&Expected> {
+        self.days.get(&day)
+    }
+}
+
+impl FromStr for AnswerSheet {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut days: BTreeMap<u8, Expected> = BTreeMap::new();
+        let mut current: Option<&mut Expected> = None;
+
+        for (number, line) in significant_lines(s) {
+            if let Some(section) = line.strip_prefix('[') {
+                let day: u8 = section
+                    .strip_suffix(']')
+                    .and_then(|section| section.strip_prefix("day"))
+                    .and_then(|day| day.parse().ok())
+                    .with_context(|| format!("Expected '[dayN]' on line {number}: {line:?}"))?;
+
+                current = Some(days.entry(day).or_default());
+                continue;
+            }
+
+            let Some(expected) = current.as_deref_mut() else {
+                bail!("Answer outside of a [dayN] section on line {number}");
+            };
+
+            expected.record(line, number)?;
+        }
+
+        Ok(AnswerSheet { days })
+    }
+}
+
+/// Numbered lines that are neither blank nor comments, trimmed.
+fn significant_lines(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    s.lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+}
@@ -0,0 +1,110 @@
+//! Plumbing shared by all the daily puzzle solutions.
+//!
+//! Every day reads its puzzle input the same way: from the file named by the first
+//! command line argument, from a per-day default file when no argument is given,
+//! or from the standard input when the argument is `-`.
+//!
+//! The standard input is only read when something is piped into it; an interactive
+//! terminal is refused rather than left waiting for the puzzle to be typed in.
+//!
+//! Each day implements [`Solution`], which lets the same code back both the binary
+//! of the day and the `aoc` runner.
+
+use std::io::{BufRead, BufReader};
+use std::{fs, io};
+
+use anyhow::{bail, Context, Result};
+use atty::Stream;
+
+pub use expected::{answer_matches, AnswerSheet, Expected};
+pub use solution::{
+    default_filename, print_answers, report_answers, run, solve, Parsed, Puzzle, Solution, Unsolved,
+};
+
+pub mod cli;
+mod expected;
+mod solution;
+#[cfg(feature = "test-support")]
+pub mod test_support;
+
+/// Name used for the standard input in error messages.
+const STDIN: &str = "<stdin>";
+
+/// Puzzle input opened for reading.
+pub struct Input {
+    name: String,
+    reader: Box<dyn BufRead>,
+}
+
+impl Input {
+    /// Opens `filename` for reading, `-` meaning the standard input.
+    pub fn open(filename: &str) -> Result<Input> {
+        if filename == "-" {
+            if atty::is(Stream::Stdin) {
+                bail!("Refusing to read puzzle input from a terminal; pipe it into stdin or pass a file name");
+            }
+
+            return Ok(Input {
+                name: STDIN.to_string(),
+                reader: Box::new(BufReader::new(io::stdin())),
+            });
+        }
+
+        let file =
+            fs::File::open(filename).with_context(|| format!("Opening file {filename:?}"))?;
+        Ok(Input {
+            name: filename.to_string(),
+            reader: Box::new(BufReader::new(file)),
+        })
+    }
+
+    /// Input read from a string already in memory, named `name` in error messages.
+    pub fn from_string(name: &str, text: String) -> Input {
+        Input {
+            name: name.to_string(),
+            reader: Box::new(io::Cursor::new(text)),
+        }
+    }
+
+    /// Name of the file the input is read from.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Iterates over the lines of the input.
+    ///
+    /// Read errors carry the line number and the name of the input as context.
+    pub fn lines(self) -> Lines {
+        Lines {
+            name: self.name,
+            number: 0,
+            lines: self.reader.lines(),
+        }
+    }
+}
+
+/// Iterator over the lines of an [`Input`].
+pub struct Lines {
+    name: String,
+    number: usize,
+    lines: io::Lines<Box<dyn BufRead>>,
+}
+
+impl Lines {
+    /// Number of the line that was returned last, starting from 1.
+    pub fn line_number(&self) -> usize {
+        self.number
+    }
+}
+
+impl Iterator for Lines {
+    type Item = Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next()?;
+        self.number += 1;
+
+        let Lines { name, number, .. } = self;
+        Some(line.with_context(|| format!("Reading line {number} of {name}")))
+    }
+}
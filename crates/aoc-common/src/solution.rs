@@ -0,0 +1,137 @@
+use std::fmt::{Display, Formatter};
+
+use anyhow::{bail, Result};
+
+use crate::{cli, Input};
+
+/// Solution to the puzzle of a single day.
+pub trait Solution {
+    /// Day of the advent calendar the puzzle belongs to.
+    const DAY: u8;
+
+    /// Title of the puzzle.
+    const TITLE: &'static str;
+
+    /// Puzzle input in the shape both parts work on.
+    type Parsed;
+
+    /// Answer to the first part of the puzzle.
+    type Part1: Display;
+
+    /// Answer to the second part of the puzzle.
+    type Part2: Display;
+
+    fn parse(input: Input) -> Result<Self::Parsed>;
+
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Part1>;
+
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Part2>;
+}
+
+/// Answer of a part that has not been solved yet.
+#[derive(Debug)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not solved yet")
+    }
+}
+
+/// Name of the file a day reads its input from when none is given.
+pub fn default_filename(day: u8) -> String {
+    format!("day{day}.txt")
+}
+
+/// [`Solution`] with its types erased, so that all the days fit in one registry.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    fn parse(&self, input: Input) -> Result<Box<dyn Parsed>>;
+}
+
+/// Parsed input of a [`Puzzle`], ready to be solved.
+pub trait Parsed {
+    fn part1(&self) -> Result<String>;
+
+    fn part2(&self) -> Result<String>;
+}
+
+impl<S> Puzzle for S
+where
+    S: Solution + Sync + 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse(&self, input: Input) -> Result<Box<dyn Parsed>> {
+        let parsed = S::parse(input)?;
+        Ok(Box::new(ParsedInput::<S>(parsed)))
+    }
+}
+
+struct ParsedInput<S: Solution>(S::Parsed);
+
+impl<S: Solution> Parsed for ParsedInput<S> {
+    fn part1(&self) -> Result<String> {
+        S::part1(&self.0).map(|answer| answer.to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        S::part2(&self.0).map(|answer| answer.to_string())
+    }
+}
+
+/// Entry point of the binary of a single day.
+///
+/// Reads the input named on the command line, or the default file of the day,
+/// and prints the answers to both parts.
+pub fn run<S: Solution>() -> Result<()> {
+    let matches = cli::command(S::DAY, S::TITLE).get_matches();
+    cli::init_logging(&matches);
+
+    solve::<S>(cli::input(&matches, S::DAY)?)
+}
+
+/// Parses the `input` and prints the answers to both parts.
+pub fn solve<S: Solution>(input: Input) -> Result<()> {
+    let parsed = S::parse(input)?;
+    print_answers::<S>(&parsed)
+}
+
+/// Prints the answers to both parts for input that has been parsed already.
+pub fn print_answers<S: Solution>(parsed: &S::Parsed) -> Result<()> {
+    report_answers(
+        S::DAY,
+        [
+            S::part1(parsed).map(|answer| answer.to_string()),
+            S::part2(parsed).map(|answer| answer.to_string()),
+        ],
+    )
+}
+
+/// Prints the answers to both parts of the puzzle of the `day` that could be found.
+///
+/// A part that fails does not keep the answer to the other one from being printed:
+/// the errors are returned once both have been printed or failed.
+pub fn report_answers(day: u8, answers: [Result<String>; 2]) -> Result<()> {
+    let mut errors = Vec::new();
+    for (part, answer) in (1..).zip(answers) {
+        match answer {
+            Ok(answer) => println!("Day {day}, part {part}: {answer}"),
+            Err(error) => errors.push(error.context(format!("Solving part {part}"))),
+        }
+    }
+
+    match <[_; 2]>::try_from(errors) {
+        Ok([first, second]) => bail!("Both parts failed:\n  {first:#}\n  {second:#}"),
+        Err(mut errors) => errors.pop().map_or(Ok(()), Err),
+    }
+}
@@ -0,0 +1,56 @@
+//! Helpers shared by the tests and benchmarks of the days, behind the `test-support` feature.
+
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+/// Xorshift generator, so that every run of a test or benchmark works on the same input.
+pub struct Rng(pub u64);
+
+impl Rng {
+    /// Next number below `n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+/// Runs the `binary` of a day with the `args`, piping the `stdin` into it when there is
+/// one, and waits for its output.
+pub fn run(binary: &str, args: &[&str], stdin: Option<&[u8]>) -> Output {
+    let mut child = Command::new(binary)
+        .args(args)
+        .stdin(if stdin.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap_or_else(|err| panic!("spawning {binary}: {err}"));
+
+    if let Some(input) = stdin {
+        child.stdin.take().unwrap().write_all(input).unwrap();
+    }
+
+    child
+        .wait_with_output()
+        .unwrap_or_else(|err| panic!("waiting for {binary}: {err}"))
+}
+
+/// Empty directory of the given `name` for a test to write its files to, left behind
+/// for inspection until the test runs again.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join("aoc-tests").join(name);
+
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap_or_else(|err| panic!("removing {dir:?}: {err}"));
+    }
+    fs::create_dir_all(&dir).unwrap_or_else(|err| panic!("creating {dir:?}: {err}"));
+
+    dir
+}
@@ -0,0 +1,105 @@
+use aoc_common::{answer_matches, AnswerSheet, Expected};
+
+#[test]
+fn expected_answers_skip_comments_and_blank_lines() {
+    let expected: Expected = "# Example\n\npart1 = 4361\n  part2=467835  \n"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        expected,
+        Expected {
+            part1: Some("4361".to_string()),
+            part2: Some("467835".to_string()),
+        }
+    );
+}
+
+#[test]
+fn missing_parts_are_left_out() {
+    let expected: Expected = "part2 = 281\n".parse().unwrap();
+
+    assert_eq!(expected.part1, None);
+    assert_eq!(expected.part2.as_deref(), Some("281"));
+    assert_eq!("".parse::<Expected>().unwrap(), Expected::default());
+}
+
+#[test]
+fn malformed_expected_lines_are_rejected_with_the_line_number() {
+    let error = "part1 = 1\npart2 281\n".parse::<Expected>().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Expected 'part = answer' on line 2: \"part2 281\""
+    );
+
+    let error = "# Parts\npart3 = 1\n".parse::<Expected>().unwrap_err();
+    assert_eq!(error.to_string(), "Unknown part \"part3\" on line 2");
+}
+
+#[test]
+fn answer_sheet_holds_the_answers_of_every_day() {
+    let sheet: AnswerSheet = "[day1]\npart1 = 54338\npart2 = 53389\n\n[day2]\npart1 = 2913\n"
+        .parse()
+        .unwrap();
+
+    let day1 = sheet.day(1).unwrap();
+    assert_eq!(day1.part1.as_deref(), Some("54338"));
+    assert_eq!(day1.part2.as_deref(), Some("53389"));
+
+    let day2 = sheet.day(2).unwrap();
+    assert_eq!(day2.part1.as_deref(), Some("2913"));
+    assert_eq!(day2.part2, None);
+
+    assert_eq!(sheet.day(3), None);
+}
+
+#[test]
+fn repeated_sections_add_to_the_same_day() {
+    let sheet: AnswerSheet = "[day1]\npart1 = 1\n[day2]\npart1 = 2\n[day1]\npart2 = 3\n"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        sheet.day(1),
+        Some(&Expected {
+            part1: Some("1".to_string()),
+            part2: Some("3".to_string()),
+        })
+    );
+}
+
+#[test]
+fn malformed_answer_sheets_are_rejected_with_the_line_number() {
+    let error = "part1 = 1\n".parse::<AnswerSheet>().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Answer outside of a [dayN] section on line 1"
+    );
+
+    let error = "[day1]\npart1 = 1\n[week2]\n"
+        .parse::<AnswerSheet>()
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Expected '[dayN]' on line 3: \"[week2]\""
+    );
+
+    let error = "[day1]\npart1: 1\n".parse::<AnswerSheet>().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Expected 'part = answer' on line 2: \"part1: 1\""
+    );
+}
+
+#[test]
+fn recorded_errors_match_failures_with_the_message() {
+    let failure = Err("Reading line 2: No digits in \"abc\"".to_string());
+
+    assert!(answer_matches("error: No digits", &failure));
+    assert!(!answer_matches("error: No numbers", &failure));
+    assert!(!answer_matches("42", &failure));
+
+    assert!(answer_matches("42", &Ok("42".to_string())));
+    assert!(!answer_matches("42", &Ok("43".to_string())));
+    assert!(!answer_matches("error: 42", &Ok("42".to_string())));
+}
@@ -0,0 +1,188 @@
+use std::hint::black_box;
+
+use aoc_common::test_support::Rng;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day1::{parse_calibration_value, parse_correct_calibration_value, Vocabulary};
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The scanners as they were before they searched from both ends, collecting every digit
+/// of the line and formatting the first and the last one into a string to parse.
+mod collecting {
+    pub fn parse_calibration_value(s: &str) -> u32 {
+        let digits: Vec<char> = s.chars().filter(|c| c.is_ascii_digit()).collect();
+
+        let first_digit = digits.first().unwrap();
+        let last_digit = digits.last().unwrap();
+
+        format!("{first_digit}{last_digit}").parse().unwrap()
+    }
+
+    pub fn parse_correct_calibration_value(s: &str) -> u32 {
+        let digits: Vec<char> = s
+            .char_indices()
+            .filter_map(|(i, c)| {
+                if c.is_ascii_digit() {
+                    Some(c)
+                } else {
+                    match c {
+                        'o' if s[i..].starts_with("one") => Some('1'),
+                        't' if s[i..].starts_with("two") => Some('2'),
+                        't' if s[i..].starts_with("three") => Some('3'),
+                        'f' if s[i..].starts_with("four") => Some('4'),
+                        'f' if s[i..].starts_with("five") => Some('5'),
+                        's' if s[i..].starts_with("six") => Some('6'),
+                        's' if s[i..].starts_with("seven") => Some('7'),
+                        'e' if s[i..].starts_with("eight") => Some('8'),
+                        'n' if s[i..].starts_with("nine") => Some('9'),
+                        _ => None,
+                    }
+                }
+            })
+            .collect();
+
+        let first_digit = digits.first().unwrap();
+        let last_digit = digits.last().unwrap();
+
+        format!("{first_digit}{last_digit}").parse().unwrap()
+    }
+}
+
+/// Searching for the first digit from the front and for the last one from the back,
+/// trying every word of the vocabulary at every byte, as part 2 did before the automaton.
+mod scanning {
+    use day1::Vocabulary;
+
+    fn digit_at(vocabulary: &Vocabulary, s: &[u8], at: usize) -> Option<u32> {
+        if s[at].is_ascii_digit() {
+            return Some(u32::from(s[at] - b'0'));
+        }
+
+        vocabulary
+            .words()
+            .find(|(word, _)| s[at..].starts_with(word.as_bytes()))
+            .map(|(_, digit)| digit)
+    }
+
+    pub fn parse_correct_calibration_value(s: &str, vocabulary: &Vocabulary) -> u32 {
+        let s = s.as_bytes();
+        let first = (0..s.len()).find_map(|at| digit_at(vocabulary, s, at));
+        let last = (0..s.len())
+            .rev()
+            .find_map(|at| digit_at(vocabulary, s, at));
+
+        first.unwrap() * 10 + last.unwrap()
+    }
+}
+
+/// Random numeric digit from 1 to 9.
+fn digit(rng: &mut Rng) -> char {
+    char::from(b'1' + rng.below(9) as u8)
+}
+
+/// Lines of letters, digits and spelled out digits, each with at least one digit.
+fn generate(lines: usize) -> Vec<String> {
+    let mut rng = Rng(0x2023_1201);
+
+    (0..lines)
+        .map(|_| {
+            let mut line = String::new();
+            for _ in 0..2 + rng.below(8) {
+                match rng.below(3) {
+                    0 => line.push(char::from(b'a' + rng.below(26) as u8)),
+                    1 => line.push(digit(&mut rng)),
+                    _ => line.push_str(WORDS[rng.below(WORDS.len())]),
+                }
+            }
+
+            let numeral = digit(&mut rng);
+            line.insert(rng.below(line.len() + 1), numeral);
+            line
+        })
+        .collect()
+}
+
+fn calibration(c: &mut Criterion) {
+    // Some 6 MB of calibration document.
+    let lines = generate(500_000);
+    let bytes: usize = lines.iter().map(|line| line.len() + 1).sum();
+    let vocabulary = Vocabulary::default();
+
+    let mut group = c.benchmark_group("calibration");
+    group.throughput(Throughput::Bytes(bytes as u64));
+    group.bench_function("collecting::parse_calibration_value", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .map(|line| collecting::parse_calibration_value(black_box(line)))
+                .sum::<u32>()
+        })
+    });
+    group.bench_function("collecting::parse_correct_calibration_value", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .map(|line| collecting::parse_correct_calibration_value(black_box(line)))
+                .sum::<u32>()
+        })
+    });
+    group.bench_function("parse_calibration_value", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .map(|line| parse_calibration_value(black_box(line)).unwrap())
+                .sum::<u32>()
+        })
+    });
+    group.bench_function("parse_correct_calibration_value", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .map(|line| parse_correct_calibration_value(black_box(line), &vocabulary).unwrap())
+                .sum::<u32>()
+        })
+    });
+    group.finish();
+}
+
+/// Searching from both ends against a single pass of the automaton, with the vocabulary
+/// of one language and of all of them.
+fn vocabulary(c: &mut Criterion) {
+    let lines = generate(500_000);
+    let bytes: usize = lines.iter().map(|line| line.len() + 1).sum();
+
+    let mut group = c.benchmark_group("vocabulary");
+    group.throughput(Throughput::Bytes(bytes as u64));
+    for (name, codes) in [("en", &["en"][..]), ("en+de+et", &["en", "de", "et"][..])] {
+        let vocabulary = Vocabulary::languages(codes).unwrap();
+
+        group.bench_function(format!("scanning/{name}"), |b| {
+            b.iter(|| {
+                lines
+                    .iter()
+                    .map(|line| {
+                        scanning::parse_correct_calibration_value(black_box(line), &vocabulary)
+                    })
+                    .sum::<u32>()
+            })
+        });
+        group.bench_function(format!("first_and_last_digit/{name}"), |b| {
+            b.iter(|| {
+                lines
+                    .iter()
+                    .map(|line| {
+                        let (first, last) =
+                            vocabulary.first_and_last_digit(black_box(line)).unwrap();
+                        first * 10 + last
+                    })
+                    .sum::<u32>()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, calibration, vocabulary);
+criterion_main!(benches);
@@ -0,0 +1,166 @@
+//! Breaks the calibration values down line by line, to find out where a wrong answer comes from.
+
+use std::fmt::Write as _;
+
+use crate::{first_and_last_numeral, CalibrationDocument, Digit};
+
+/// Digits read from a line of the calibration document, and what they are worth.
+#[derive(Debug)]
+pub struct Explanation<'a> {
+    /// Line number in the document.
+    pub number: usize,
+    pub line: &'a str,
+    /// First and last numeric digit, which part 1 reads as
+    /// [`parse_calibration_value`](crate::parse_calibration_value) does.
+    pub numerals: Option<(Digit, Digit)>,
+    /// First and last digit, numeric or spelled out, which part 2 reads as
+    /// [`parse_correct_calibration_value`](crate::parse_correct_calibration_value) does.
+    pub digits: Option<(Digit, Digit)>,
+}
+
+impl Explanation<'_> {
+    /// Calibration value of the line in part 1, or `None` when it has no numeric digits.
+    pub fn part1(&self) -> Option<u32> {
+        self.numerals.map(value)
+    }
+
+    /// Calibration value of the line in part 2, or `None` when it has no digits at all.
+    pub fn part2(&self) -> Option<u32> {
+        self.digits.map(value)
+    }
+
+    /// Position of the `digit` in the line in characters, counting from 1.
+    pub fn column(&self, digit: &Digit) -> usize {
+        self.line[..digit.start].chars().count() + 1
+    }
+}
+
+/// Explanations of all the lines of the `document`, including the ones without any digits.
+pub fn explain(document: &CalibrationDocument) -> Vec<Explanation<'_>> {
+    document
+        .lines
+        .iter()
+        .map(|(number, line)| Explanation {
+            number: *number,
+            line,
+            numerals: first_and_last_numeral(line),
+            digits: document.vocabulary.first_and_last(line),
+        })
+        .collect()
+}
+
+/// Explanations as a table with a row for every line, and the sums of both parts at the end.
+///
+/// Each part has the first and the last digit it reads, with the column it was found at.
+pub fn table(explanations: &[Explanation]) -> String {
+    let mut table = String::new();
+    let _ = writeln!(
+        table,
+        "{:>6}  {:<10}  {:<10}  {:>5}  {:<18}  {:<18}  {:>5}  text",
+        "line", "first", "last", "part1", "first", "last", "part2"
+    );
+
+    let (mut part1, mut part2) = (0, 0);
+    for explanation in explanations {
+        let digit = |digit: Option<Digit>, with_source: bool| match digit {
+            Some(digit) if with_source => format!(
+                "{} at {} ({})",
+                digit.value,
+                explanation.column(&digit),
+                source(&digit)
+            ),
+            Some(digit) => format!("{} at {}", digit.value, explanation.column(&digit)),
+            None => "-".to_string(),
+        };
+        let value = |value: Option<u32>| value.map_or("-".to_string(), |value| value.to_string());
+
+        let _ = writeln!(
+            table,
+            "{:>6}  {:<10}  {:<10}  {:>5}  {:<18}  {:<18}  {:>5}  {}",
+            explanation.number,
+            digit(explanation.numerals.map(|(first, _)| first), false),
+            digit(explanation.numerals.map(|(_, last)| last), false),
+            value(explanation.part1()),
+            digit(explanation.digits.map(|(first, _)| first), true),
+            digit(explanation.digits.map(|(_, last)| last), true),
+            value(explanation.part2()),
+            explanation.line
+        );
+
+        part1 += explanation.part1().unwrap_or(0);
+        part2 += explanation.part2().unwrap_or(0);
+    }
+
+    let _ = writeln!(
+        table,
+        "{:>6}  {:<10}  {:<10}  {part1:>5}  {:<18}  {:<18}  {part2:>5}",
+        "total", "", "", "", ""
+    );
+
+    table
+}
+
+/// Explanations as CSV with a header and a record for every line. The fields of a part
+/// that finds no digits in the line are left empty.
+pub fn csv(explanations: &[Explanation]) -> String {
+    let mut csv = String::from(concat!(
+        "line,",
+        "part1_first,part1_first_column,part1_last,part1_last_column,part1,",
+        "part2_first,part2_first_column,part2_first_source,",
+        "part2_last,part2_last_column,part2_last_source,part2,",
+        "text\n",
+    ));
+
+    for explanation in explanations {
+        let numeral = |digit: Option<Digit>| match digit {
+            Some(digit) => format!("{},{}", digit.value, explanation.column(&digit)),
+            None => ",".to_string(),
+        };
+        let digit = |digit: Option<Digit>| match digit {
+            Some(digit) => format!(
+                "{},{},{}",
+                digit.value,
+                explanation.column(&digit),
+                source(&digit)
+            ),
+            None => ",,".to_string(),
+        };
+        let value = |value: Option<u32>| value.map_or(String::new(), |value| value.to_string());
+
+        let _ = writeln!(
+            csv,
+            "{},{},{},{},{},{},{},{}",
+            explanation.number,
+            numeral(explanation.numerals.map(|(first, _)| first)),
+            numeral(explanation.numerals.map(|(_, last)| last)),
+            value(explanation.part1()),
+            digit(explanation.digits.map(|(first, _)| first)),
+            digit(explanation.digits.map(|(_, last)| last)),
+            value(explanation.part2()),
+            quote(explanation.line)
+        );
+    }
+
+    csv
+}
+
+fn value((first, last): (Digit, Digit)) -> u32 {
+    first.value * 10 + last.value
+}
+
+fn source(digit: &Digit) -> &'static str {
+    if digit.spelled {
+        "word"
+    } else {
+        "numeral"
+    }
+}
+
+/// Quotes a CSV field when it holds a character that would break the record up.
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -0,0 +1,131 @@
+//! Day 1: Trebuchet?!
+
+use anyhow::{Context, Result};
+use aoc_common::{Input, Solution};
+use log::warn;
+
+pub use vocabulary::{Digit, Vocabulary, ENGLISH, ESTONIAN, GERMAN, LANGUAGES};
+
+pub mod explain;
+mod vocabulary;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    type Parsed = CalibrationDocument;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: Input) -> Result<Self::Parsed> {
+        let mut lines = Vec::new();
+        let mut input = input.lines();
+        while let Some(line) = input.next() {
+            let line = line?;
+            if !line.is_empty() {
+                lines.push((input.line_number(), line));
+            }
+        }
+
+        Ok(CalibrationDocument {
+            lines,
+            vocabulary: Vocabulary::default(),
+            skip_invalid: false,
+        })
+    }
+
+    fn part1(document: &Self::Parsed) -> Result<Self::Part1> {
+        part1(document)
+    }
+
+    fn part2(document: &Self::Parsed) -> Result<Self::Part2> {
+        part2(document)
+    }
+}
+
+/// Lines of the calibration document, and how to read the calibration values from them.
+#[derive(Debug)]
+pub struct CalibrationDocument {
+    /// Non-empty lines of the document, with their line numbers.
+    pub lines: Vec<(usize, String)>,
+    /// Words the digits may be spelled out with in part 2.
+    pub vocabulary: Vocabulary,
+    /// Whether a line without any digits is skipped with a warning, as if it was worth 0,
+    /// rather than failing the whole part.
+    pub skip_invalid: bool,
+}
+
+impl CalibrationDocument {
+    /// Sum of the values that `value` reads from the lines, skipping or failing on the lines
+    /// it rejects as chosen with [`skip_invalid`](CalibrationDocument::skip_invalid).
+    fn sum(&self, value: impl Fn(&str) -> Result<u32>) -> Result<u32> {
+        let mut sum = 0;
+        for (number, line) in &self.lines {
+            match value(line) {
+                Ok(value) => sum += value,
+                Err(error) if self.skip_invalid => warn!("Skipping line {number}: {error}"),
+                Err(error) => {
+                    return Err(error)
+                        .with_context(|| format!("Reading the calibration value on line {number}"))
+                }
+            }
+        }
+
+        Ok(sum)
+    }
+}
+
+/// Sum of the calibration values made of numeric digits only.
+pub fn part1(document: &CalibrationDocument) -> Result<u32> {
+    document.sum(parse_calibration_value)
+}
+
+/// Sum of the calibration values made of both numeric digits and the digits spelled out
+/// with the words of the vocabulary of the document.
+pub fn part2(document: &CalibrationDocument) -> Result<u32> {
+    document.sum(|line| parse_correct_calibration_value(line, &document.vocabulary))
+}
+
+/// Combines the first and the last numeric digit of the line into a two-digit number.
+///
+/// Fails if the line does not contain any numeric digits.
+pub fn parse_calibration_value(s: &str) -> Result<u32> {
+    let (first, last) =
+        first_and_last_numeral(s).with_context(|| format!("No numeric digits in {s:?}"))?;
+
+    Ok(first.value * 10 + last.value)
+}
+
+/// First and last numeric digit of the line along with where they were found, searching
+/// from the front and from the back.
+pub fn first_and_last_numeral(s: &str) -> Option<(Digit, Digit)> {
+    let bytes = s.as_bytes();
+    let numeral = |at: usize| Digit {
+        value: u32::from(bytes[at] - b'0'),
+        start: at,
+        end: at + 1,
+        spelled: false,
+    };
+
+    let first = bytes.iter().position(u8::is_ascii_digit)?;
+    let last = bytes.iter().rposition(u8::is_ascii_digit).unwrap_or(first);
+    Some((numeral(first), numeral(last)))
+}
+
+/// Combines the first and the last digit of the line into a two-digit number,
+/// where digits may also be spelled out with the words of the `vocabulary`.
+///
+/// Spelled out digits may share letters, so `eightwo` has both an 8 and a 2 in it:
+/// all the digits are found in a single pass, overlapping ones included, and the ones
+/// starting first and last are kept.
+///
+/// Fails if the line does not contain any digits, numeric or spelled out.
+pub fn parse_correct_calibration_value(s: &str, vocabulary: &Vocabulary) -> Result<u32> {
+    let (first_digit, last_digit) = vocabulary
+        .first_and_last_digit(s)
+        .with_context(|| format!("No digits in {s:?}"))?;
+
+    Ok(first_digit * 10 + last_digit)
+}
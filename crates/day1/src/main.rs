@@ -1,80 +1,53 @@
-use std::io::{BufRead, BufReader};
-use std::{env, fs, io};
-
-use atty::Stream;
-
-const DEFAULT_FILENAME: &str = "day1.txt";
-
-fn parse_calibration_value(s: &str) -> u32 {
-    let digits: Vec<char> = s
-        .char_indices()
-        .filter_map(|(_, c)| match "0123456789".find(c) {
-            Some(_) => Some(c),
-            None => None,
-        })
-        .collect();
-
-    if digits.is_empty() {
-        0
-    } else {
-        let first_digit = digits.first().unwrap();
-        let last_digit = digits.last().unwrap();
-
-        format!("{first_digit}{last_digit}").parse().unwrap()
+use std::fs;
+
+use anyhow::{Context, Result};
+use aoc_common::{cli, Solution};
+use clap::{arg, ArgAction};
+use day1::{explain, Day1, Vocabulary, LANGUAGES};
+
+fn main() -> Result<()> {
+    let matches = cli::command(Day1::DAY, Day1::TITLE)
+        .arg(
+            arg!(-l --language <CODE> "Languages the digits are spelled out in for part 2, like en,de [default: en]")
+                .value_parser(LANGUAGES.iter().map(|(code, _)| *code).collect::<Vec<_>>())
+                .value_delimiter(',')
+                .action(ArgAction::Append),
+        )
+        .arg(
+            arg!(-w --words <FILE> "File with the 'word = digit' lines to spell out digits with for part 2")
+                .conflicts_with("language"),
+        )
+        .arg(arg!(--"skip-invalid" "Skips lines without any digits with a warning instead of failing"))
+        .arg(
+            arg!(--explain [FORMAT] "Prints the digits read from every line and what it adds to both parts instead of the answers")
+                .value_parser(["table", "csv"])
+                .default_missing_value("table"),
+        )
+        .get_matches();
+    cli::init_logging(&matches);
+
+    let mut document = Day1::parse(cli::input(&matches, Day1::DAY)?)?;
+    document.skip_invalid = matches.get_flag("skip-invalid");
+    if let Some(codes) = matches.get_many::<String>("language") {
+        let codes: Vec<&str> = codes.map(String::as_str).collect();
+        document.vocabulary = Vocabulary::languages(&codes).context("Unknown language")?;
+    }
+    if let Some(filename) = matches.get_one::<String>("words") {
+        let text = fs::read_to_string(filename).with_context(|| format!("Reading {filename:?}"))?;
+        document.vocabulary = text
+            .parse()
+            .with_context(|| format!("Parsing the vocabulary in {filename:?}"))?;
     }
-}
-
-fn parse_correct_calibration_value(s: &str) -> u32 {
-    let digits: Vec<char> = s
-        .char_indices()
-        .filter_map(|(i, c)| {
-            if let Some(_) = "0123456789".find(c) {
-                Some(c)
-            } else {
-                match c {
-                    'o' if s[i..].starts_with("one") => Some('1'),
-                    't' if s[i..].starts_with("two") => Some('2'),
-                    't' if s[i..].starts_with("three") => Some('3'),
-                    'f' if s[i..].starts_with("four") => Some('4'),
-                    'f' if s[i..].starts_with("five") => Some('5'),
-                    's' if s[i..].starts_with("six") => Some('6'),
-                    's' if s[i..].starts_with("seven") => Some('7'),
-                    'e' if s[i..].starts_with("eight") => Some('8'),
-                    'n' if s[i..].starts_with("nine") => Some('9'),
-                    _ => None,
-                }
-            }
-        })
-        .collect();
-
-    let first_digit = digits.first().unwrap();
-    let last_digit = digits.last().unwrap();
-
-    format!("{first_digit}{last_digit}").parse().unwrap()
-}
 
-fn main() {
-    let filename = env::args()
-        .skip(1)
-        .next()
-        .unwrap_or_else(|| DEFAULT_FILENAME.to_string());
-    let reader: Box<dyn BufRead> = if filename == "-" && atty::is(Stream::Stdin) {
-        Box::new(BufReader::new(io::stdin()))
-    } else {
-        Box::new(BufReader::new(fs::File::open(filename).unwrap()))
+    let Some(format) = matches.get_one::<String>("explain") else {
+        return aoc_common::print_answers::<Day1>(&document);
     };
 
-    let mut part1: u32 = 0;
-    let mut part2: u32 = 0;
-    for line in reader.lines() {
-        if let Ok(l) = line {
-            if !l.is_empty() {
-                part1 += parse_calibration_value(&l);
-                part2 += parse_correct_calibration_value(&l);
-            }
-        }
+    let explanations = explain::explain(&document);
+    match format.as_str() {
+        "csv" => print!("{}", explain::csv(&explanations)),
+        _ => print!("{}", explain::table(&explanations)),
     }
 
-    println!("Part 1: {part1}");
-    println!("Part 2: {part2}");
+    Ok(())
 }
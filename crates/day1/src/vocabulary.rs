@@ -0,0 +1,203 @@
+use std::str::FromStr;
+
+use anyhow::{bail, ensure, Context, Result};
+use wordmatch::{Automaton, Match};
+
+/// Digits spelled out in English, as in the puzzle.
+pub const ENGLISH: &[(&str, u32)] = &[
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// Digits spelled out in German.
+pub const GERMAN: &[(&str, u32)] = &[
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+/// Digits spelled out in Estonian.
+pub const ESTONIAN: &[(&str, u32)] = &[
+    ("üks", 1),
+    ("kaks", 2),
+    ("kolm", 3),
+    ("neli", 4),
+    ("viis", 5),
+    ("kuus", 6),
+    ("seitse", 7),
+    ("kaheksa", 8),
+    ("üheksa", 9),
+];
+
+/// Number of numeric digits, which the automaton of a vocabulary finds besides its words.
+const NUMERALS: usize = 10;
+
+/// Built-in vocabularies by their language code.
+pub const LANGUAGES: &[(&str, &[(&str, u32)])] =
+    &[("en", ENGLISH), ("de", GERMAN), ("et", ESTONIAN)];
+
+/// Words that digits may be spelled out with, along with the digit each one stands for.
+///
+/// Besides the built-in [`LANGUAGES`], a vocabulary can be read from a file like
+///
+/// ```text
+/// # Digits in Estonian
+/// üks = 1
+/// kaks = 2
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+    /// Automaton finding the words and the numeric digits at once.
+    automaton: Automaton<u32>,
+}
+
+impl Vocabulary {
+    pub fn new(words: &[(&str, u32)]) -> Vocabulary {
+        Vocabulary::from_words(
+            words
+                .iter()
+                .map(|&(word, digit)| (word.to_string(), digit))
+                .collect(),
+        )
+    }
+
+    fn from_words(words: Vec<(String, u32)>) -> Vocabulary {
+        let numerals = (0..NUMERALS as u32).map(|digit| (digit.to_string(), digit));
+        let automaton = Automaton::new(numerals.chain(words.iter().cloned()));
+
+        Vocabulary { words, automaton }
+    }
+
+    /// Built-in vocabulary of the language with the given code, like `en`.
+    pub fn language(code: &str) -> Option<Vocabulary> {
+        Vocabulary::languages(&[code])
+    }
+
+    /// Built-in vocabularies of all the languages with the given codes in one.
+    pub fn languages(codes: &[&str]) -> Option<Vocabulary> {
+        let mut words = Vec::new();
+        for code in codes {
+            let (_, language) = LANGUAGES.iter().find(|(language, _)| language == code)?;
+            words.extend(
+                language
+                    .iter()
+                    .map(|&(word, digit)| (word.to_string(), digit)),
+            );
+        }
+
+        Some(Vocabulary::from_words(words))
+    }
+
+    /// Words of the vocabulary and the digits they stand for.
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, digit)| (word.as_str(), *digit))
+    }
+
+    /// Numeric and spelled out digits of `s`, including the ones whose words overlap,
+    /// found in a single pass in the order the words end.
+    pub fn digits<'a>(&'a self, s: &'a str) -> impl Iterator<Item = u32> + 'a {
+        self.automaton.find_overlapping(s).map(|found| *found.value)
+    }
+
+    /// Digits of `s` starting first and last, found in a single pass whatever the size of
+    /// the vocabulary.
+    pub fn first_and_last_digit(&self, s: &str) -> Option<(u32, u32)> {
+        self.first_and_last(s)
+            .map(|(first, last)| (first.value, last.value))
+    }
+
+    /// Digits of `s` starting first and last along with where they were found, in a
+    /// single pass like [`first_and_last_digit`](Vocabulary::first_and_last_digit).
+    pub fn first_and_last(&self, s: &str) -> Option<(Digit, Digit)> {
+        let mut matches = self.automaton.find_overlapping(s);
+        let found = matches.next()?;
+
+        let (mut first, mut last) = (found, found);
+        for found in matches {
+            if found.start < first.start {
+                first = found;
+            }
+            if found.start > last.start {
+                last = found;
+            }
+        }
+
+        let digit = |found: Match<u32>| Digit {
+            value: *found.value,
+            start: found.start,
+            end: found.end,
+            // The numerals are the first words of the automaton.
+            spelled: found.word >= NUMERALS,
+        };
+        Some((digit(first), digit(last)))
+    }
+}
+
+/// Digit found in a line, either numeric or spelled out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Digit {
+    pub value: u32,
+    /// Byte offset of the digit in the line.
+    pub start: usize,
+    /// Byte offset just after the end of the digit.
+    pub end: usize,
+    /// Whether the digit was spelled out with a word rather than written as a numeral.
+    pub spelled: bool,
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Vocabulary::new(ENGLISH)
+    }
+}
+
+impl FromStr for Vocabulary {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = Vec::new();
+
+        for (index, line) in s.lines().enumerate() {
+            let number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (word, digit) = line
+                .split_once('=')
+                .with_context(|| format!("Expected 'word = digit' on line {number}: {line:?}"))?;
+            let (word, digit) = (word.trim(), digit.trim());
+
+            ensure!(!word.is_empty(), "Missing word on line {number}");
+            let digit: u32 = match digit.parse() {
+                Ok(digit) if digit <= 9 => digit,
+                _ => bail!("Expected a digit from 0 to 9 on line {number}, but got {digit:?}"),
+            };
+
+            words.push((word.to_string(), digit));
+        }
+
+        ensure!(
+            !words.is_empty(),
+            "Expected at least one word in the vocabulary"
+        );
+        Ok(Vocabulary::from_words(words))
+    }
+}
@@ -0,0 +1,95 @@
+use day1::explain::{self, Explanation};
+use day1::{parse_calibration_value, CalibrationDocument, Digit, Vocabulary};
+
+fn document(text: &str) -> CalibrationDocument {
+    CalibrationDocument {
+        lines: text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.to_string()))
+            .collect(),
+        vocabulary: Vocabulary::default(),
+        skip_invalid: false,
+    }
+}
+
+fn digits(explanation: &Explanation) -> Vec<(u32, usize, bool)> {
+    columns(explanation.digits.unwrap(), explanation)
+}
+
+fn numerals(explanation: &Explanation) -> Vec<(u32, usize, bool)> {
+    columns(explanation.numerals.unwrap(), explanation)
+}
+
+fn columns((first, last): (Digit, Digit), explanation: &Explanation) -> Vec<(u32, usize, bool)> {
+    [first, last]
+        .iter()
+        .map(|digit| (digit.value, explanation.column(digit), digit.spelled))
+        .collect()
+}
+
+#[test]
+fn digits_are_explained_with_their_columns_and_sources() {
+    let document = document("zoneight234\neightwothree\nabc");
+    let explanations = explain::explain(&document);
+
+    assert_eq!(digits(&explanations[0]), [(1, 2, true), (4, 11, false)]);
+    assert_eq!(numerals(&explanations[0]), [(2, 9, false), (4, 11, false)]);
+    assert_eq!(explanations[0].part1(), Some(24));
+    assert_eq!(explanations[0].part2(), Some(14));
+
+    assert_eq!(digits(&explanations[1]), [(8, 1, true), (3, 8, true)]);
+    assert!(explanations[1].numerals.is_none());
+    assert_eq!(explanations[1].part1(), None);
+    assert_eq!(explanations[1].part2(), Some(83));
+
+    assert!(explanations[2].digits.is_none());
+    assert_eq!(explanations[2].part2(), None);
+}
+
+#[test]
+fn columns_count_characters_rather_than_bytes() {
+    let mut document = document("ü3zwei");
+    document.vocabulary = Vocabulary::language("de").unwrap();
+    let explanations = explain::explain(&document);
+
+    assert_eq!(digits(&explanations[0]), [(3, 2, false), (2, 3, true)]);
+}
+
+#[test]
+fn csv_has_a_record_for_every_line() {
+    let document = document("two1nine\nabc,\"x\"");
+    let csv = explain::csv(&explain::explain(&document));
+
+    assert_eq!(
+        csv,
+        "line,part1_first,part1_first_column,part1_last,part1_last_column,part1,\
+         part2_first,part2_first_column,part2_first_source,\
+         part2_last,part2_last_column,part2_last_source,part2,text\n\
+         1,1,4,1,4,11,2,1,word,9,5,word,29,two1nine\n\
+         2,,,,,,,,,,,,,\"abc,\"\"x\"\"\"\n"
+    );
+}
+
+#[test]
+fn table_ends_with_the_sums_of_both_parts() {
+    let document = document("two1nine\n7pqrstsixteen\nabc");
+    let table = explain::table(&explain::explain(&document));
+
+    let total: Vec<&str> = table.lines().last().unwrap().split_whitespace().collect();
+    assert_eq!(total, ["total", "88", "105"]);
+}
+
+#[test]
+fn part_1_is_explained_the_way_it_is_answered() {
+    let document = document("a1b2c3\nx7y\nnone\ntwo1nine");
+
+    for explanation in explain::explain(&document) {
+        assert_eq!(
+            explanation.part1(),
+            parse_calibration_value(explanation.line).ok(),
+            "{}",
+            explanation.line
+        );
+    }
+}
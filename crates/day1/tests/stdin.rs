@@ -0,0 +1,124 @@
+use std::process::Output;
+
+use aoc_common::test_support;
+
+const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../test/day1.txt");
+const EXPECTED: &str = "Day 1, part 1: 142\nDay 1, part 2: 142\n";
+
+fn run(args: &[&str], stdin: Option<&[u8]>) -> Output {
+    test_support::run(env!("CARGO_BIN_EXE_day1"), args, stdin)
+}
+
+#[test]
+fn reads_piped_stdin_for_dash() {
+    let input = std::fs::read(FIXTURE).unwrap();
+    let output = run(&["-"], Some(&input));
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), EXPECTED);
+}
+
+#[test]
+fn reads_file_named_by_argument() {
+    let output = run(&[FIXTURE], None);
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), EXPECTED);
+}
+
+#[test]
+fn line_without_digits_fails_with_its_line_number() {
+    let output = run(&["-"], Some(b"1abc2\n\nno digits here\n"));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("Reading the calibration value on line 3"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("No numeric digits in \"no digits here\""),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn part_2_is_answered_even_when_part_1_fails() {
+    let output = run(
+        &["-"],
+        Some(
+            b"eightwothree
+4nine
+",
+        ),
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Day 1, part 2: 132\n"
+    );
+    assert!(stderr.contains("Solving part 1"), "{stderr}");
+}
+
+#[test]
+fn skip_invalid_skips_lines_without_digits() {
+    let output = run(&["-", "--skip-invalid"], Some(b"1abc2\nno digits\none\n"));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "{stderr}");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Day 1, part 1: 12\nDay 1, part 2: 23\n"
+    );
+    assert!(
+        stderr.contains("Skipping line 2: No numeric digits in \"no digits\""),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("Skipping line 3: No numeric digits in \"one\""),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn spells_digits_in_several_languages_at_once() {
+    let output = run(
+        &["-", "--language", "en,de"],
+        Some(b"eins5two\nthree1zwei\n"),
+    );
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Day 1, part 1: 66\nDay 1, part 2: 44\n"
+    );
+}
+
+#[test]
+fn explains_every_line_instead_of_answering() {
+    let output = run(&["-", "--explain", "csv"], Some(b"eightwothree\n"));
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).lines().nth(1),
+        Some("1,,,,,,8,1,word,3,8,word,83,eightwothree")
+    );
+}
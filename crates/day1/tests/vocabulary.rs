@@ -0,0 +1,98 @@
+use day1::{parse_correct_calibration_value, Vocabulary, ESTONIAN, GERMAN};
+
+fn english(line: &str) -> u32 {
+    parse_correct_calibration_value(line, &Vocabulary::default()).unwrap()
+}
+
+#[test]
+fn overlapping_words_both_count() {
+    assert_eq!(english("eightwo"), 82);
+    assert_eq!(english("twone"), 21);
+    assert_eq!(english("oneight"), 18);
+    assert_eq!(english("sevenine"), 79);
+    assert_eq!(english("xtwone3four"), 24);
+    assert_eq!(english("zoneight234"), 14);
+}
+
+#[test]
+fn overlapping_words_are_all_found_in_order() {
+    let vocabulary = Vocabulary::default();
+    let digits: Vec<u32> = vocabulary.digits("eightwothreeightwone").collect();
+
+    assert_eq!(digits, [8, 2, 3, 8, 2, 1]);
+}
+
+#[test]
+fn other_languages_spell_out_their_own_digits() {
+    assert_eq!(
+        parse_correct_calibration_value("xfünfzweiy", &Vocabulary::new(GERMAN)).unwrap(),
+        52
+    );
+    assert_eq!(
+        parse_correct_calibration_value("üheksakolm7", &Vocabulary::new(ESTONIAN)).unwrap(),
+        97
+    );
+    assert_eq!(
+        parse_correct_calibration_value("kaheksaüks", &Vocabulary::new(ESTONIAN)).unwrap(),
+        81
+    );
+    assert_eq!(Vocabulary::language("et"), Some(Vocabulary::new(ESTONIAN)));
+    assert_eq!(Vocabulary::language("xx"), None);
+}
+
+#[test]
+fn vocabulary_is_read_from_word_digit_lines() {
+    let vocabulary: Vocabulary = "# Roman numerals\nI = 1\n\nV = 5\n".parse().unwrap();
+
+    assert_eq!(vocabulary.words().collect::<Vec<_>>(), [("I", 1), ("V", 5)]);
+    assert_eq!(
+        parse_correct_calibration_value("xVIIy", &vocabulary).unwrap(),
+        51
+    );
+}
+
+#[test]
+fn malformed_vocabulary_is_rejected_with_the_line_number() {
+    let error = "one = 1\ntwo = 12\n".parse::<Vocabulary>().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Expected a digit from 0 to 9 on line 2, but got \"12\""
+    );
+
+    let error = "one = 1\ntwo\n".parse::<Vocabulary>().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Expected 'word = digit' on line 2: \"two\""
+    );
+}
+
+#[test]
+fn single_pass_agrees_with_trying_every_word_everywhere() {
+    let vocabulary = Vocabulary::languages(&["en", "de", "et"]).unwrap();
+    for line in [
+        "eightwothree",
+        "x1y",
+        "nodigits",
+        "fünfoneightkolm",
+        "7pqrstsixteen",
+        "zweiüheksa",
+    ] {
+        let digits: Vec<u32> = (0..line.len())
+            .filter_map(|at| {
+                let rest = &line.as_bytes()[at..];
+                let numeral = rest[0].is_ascii_digit().then(|| u32::from(rest[0] - b'0'));
+                numeral.or_else(|| {
+                    vocabulary
+                        .words()
+                        .find(|(word, _)| rest.starts_with(word.as_bytes()))
+                        .map(|(_, digit)| digit)
+                })
+            })
+            .collect();
+        let expected = digits
+            .first()
+            .zip(digits.last())
+            .map(|(&first, &last)| (first, last));
+        assert_eq!(vocabulary.first_and_last_digit(line), expected, "{line}");
+    }
+}
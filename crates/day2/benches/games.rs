@@ -0,0 +1,49 @@
+use std::hint::black_box;
+
+use aoc_common::test_support::Rng;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day2::Game;
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+/// Games of one to six sets, each drawing one to three colours.
+fn generate(games: usize) -> Vec<String> {
+    let mut rng = Rng(0x2023_1202);
+
+    (1..=games)
+        .map(|id| {
+            let sets: Vec<String> = (0..1 + rng.below(6))
+                .map(|_| {
+                    let colors = 1 + rng.below(3);
+                    let offset = rng.below(3);
+                    (0..colors)
+                        .map(|c| format!("{} {}", 1 + rng.below(20), COLORS[(offset + c) % 3]))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect();
+
+            format!("Game {id}: {}", sets.join("; "))
+        })
+        .collect()
+}
+
+fn games(c: &mut Criterion) {
+    let lines = generate(10_000);
+    let bytes: usize = lines.iter().map(|line| line.len() + 1).sum();
+
+    let mut group = c.benchmark_group("games");
+    group.throughput(Throughput::Bytes(bytes as u64));
+    group.bench_function("Game::from_str", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .map(|line| black_box(line).parse::<Game>().unwrap().id)
+                .sum::<u32>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, games);
+criterion_main!(benches);
@@ -0,0 +1,356 @@
+//! Day 2: Cube Conundrum
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::{Display, Formatter};
+use std::ops::{AddAssign, Range};
+use std::str::FromStr;
+
+use anyhow::{bail, ensure, Context, Result};
+use aoc_common::{Input, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    type Parsed = GameRecord;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: Input) -> Result<Self::Parsed> {
+        parse(input, false)
+    }
+
+    fn part1(record: &Self::Parsed) -> Result<Self::Part1> {
+        Ok(part1(record))
+    }
+
+    fn part2(record: &Self::Parsed) -> Result<Self::Part2> {
+        Ok(part2(record))
+    }
+}
+
+/// Parses the games of the `input`, to be checked against the bag of the puzzle.
+///
+/// When `strict`, a colour drawn twice in the same set is rejected rather than having its
+/// counts added up, and the games have to be numbered 1, 2, 3 and so on, each only once.
+pub fn parse(input: Input, strict: bool) -> Result<GameRecord> {
+    let mut games: Vec<Game> = Vec::new();
+    let mut lines_of_ids: HashMap<u32, usize> = HashMap::new();
+
+    let mut input = input.lines();
+    while let Some(line) = input.next() {
+        let line = line?;
+        if line.is_empty() {
+            continue;
+        }
+
+        let number = input.line_number();
+        let game = Game::parse(&line, strict)
+            .with_context(|| format!("Parsing the game on line {number}"))?;
+
+        if strict {
+            let check = || -> Result<()> {
+                if let Some(first) = lines_of_ids.get(&game.id) {
+                    bail!("Game {} was already recorded on line {first}", game.id);
+                }
+
+                let expected = games.len() + 1;
+                ensure!(
+                    game.id as usize == expected,
+                    "Expected game {expected}, but got game {}",
+                    game.id
+                );
+                Ok(())
+            };
+            check().with_context(|| format!("Checking the id of the game on line {number}"))?;
+            lines_of_ids.insert(game.id, number);
+        }
+
+        games.push(game);
+    }
+
+    Ok(GameRecord {
+        games,
+        bag: LIMITS.iter().copied().collect(),
+    })
+}
+
+/// Cubes in the bag in the puzzle: 12 red, 13 green and 14 blue.
+pub const LIMITS: &[(&str, u32)] = &[("red", 12), ("green", 13), ("blue", 14)];
+
+/// Games played, and the bag of cubes they are checked against in part 1.
+#[derive(Debug)]
+pub struct GameRecord {
+    pub games: Vec<Game>,
+    /// Number of cubes of every colour in the bag. There are no cubes of the other colours.
+    pub bag: Set,
+}
+
+impl GameRecord {
+    /// Colours drawn in any of the games.
+    pub fn colors(&self) -> BTreeSet<&str> {
+        self.games
+            .iter()
+            .flat_map(|game| &game.sets)
+            .flat_map(Set::colors)
+            .collect()
+    }
+}
+
+/// Sum of the ids of the games that are possible with the cubes in the bag.
+pub fn part1(record: &GameRecord) -> u32 {
+    record
+        .games
+        .iter()
+        .filter(|game| game.max().fits_in(&record.bag))
+        .map(|game| game.id)
+        .sum()
+}
+
+/// Sum of the powers of the smallest sets of cubes each game could have been played with.
+///
+/// The power counts every colour drawn in any of the games, so it is 0 for a game
+/// that never draws one of them.
+pub fn part2(record: &GameRecord) -> u32 {
+    let colors = record.colors();
+    record
+        .games
+        .iter()
+        .map(|game| game.max().power(colors.iter().copied()))
+        .sum()
+}
+
+/// Reads the bag from lines like `red = 12`, giving the number of cubes of each colour.
+///
+/// Blank lines and lines starting with `#` are ignored.
+pub fn parse_limits(s: &str) -> Result<Set> {
+    let mut bag = Set::default();
+    for (index, line) in s.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (color, count) = parse_limit(line)
+            .with_context(|| format!("Reading the limit on line {}", index + 1))?;
+        bag.insert(color, count);
+    }
+
+    Ok(bag)
+}
+
+/// Reads the number of cubes of a colour in the bag from `color = count`, like `red=12`.
+pub fn parse_limit(s: &str) -> Result<(String, u32)> {
+    let (color, count) = s
+        .split_once('=')
+        .with_context(|| format!("Expected 'color = count', but got {s:?}"))?;
+
+    let color = color.trim();
+    ensure!(!color.is_empty(), "Missing the color in {s:?}");
+    let count = count
+        .trim()
+        .parse()
+        .with_context(|| format!("Not a number of cubes: {:?}", count.trim()))?;
+
+    Ok((color.to_string(), count))
+}
+
+/// Counts of cubes by colour.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct Set(BTreeMap<String, u32>);
+
+impl Set {
+    /// Number of cubes of the `color`, which is 0 for a colour the set does not have.
+    pub fn count(&self, color: &str) -> u32 {
+        self.0.get(color).copied().unwrap_or(0)
+    }
+
+    /// Replaces the number of cubes of the `color`.
+    pub fn insert(&mut self, color: impl Into<String>, count: u32) {
+        self.0.insert(color.into(), count);
+    }
+
+    /// Colours of the set, in alphabetical order.
+    pub fn colors(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    /// Colours of the set along with the number of cubes of each.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.0.iter().map(|(color, count)| (color.as_str(), *count))
+    }
+
+    /// Whether the set could have been drawn from a bag holding the cubes of the `bag`.
+    pub fn fits_in(&self, bag: &Set) -> bool {
+        self.iter().all(|(color, count)| count <= bag.count(color))
+    }
+
+    /// Product of the numbers of cubes of the `colors`.
+    pub fn power<'a>(&self, colors: impl IntoIterator<Item = &'a str>) -> u32 {
+        colors.into_iter().map(|color| self.count(color)).product()
+    }
+
+    /// Largest number of cubes of every colour in either set.
+    pub fn max(mut self, other: &Set) -> Set {
+        for (color, count) in other.iter() {
+            let most = self.0.entry(color.to_string()).or_default();
+            *most = (*most).max(count);
+        }
+
+        self
+    }
+}
+
+impl Set {
+    /// Parses the cubes drawn at the byte `range` of the `line`, like `3 blue, 4 red`.
+    ///
+    /// The counts of a colour drawn more than once are added up, unless `strict`, in which
+    /// case that fails naming the columns of the line the colour was drawn at.
+    fn parse(line: &str, range: Range<usize>, strict: bool) -> Result<Set> {
+        let mut set: Set = Default::default();
+        let mut columns: HashMap<&str, usize> = HashMap::new();
+
+        let s = &line[range.clone()];
+        let mut at = range.start + s.len() - s.trim_start().len();
+
+        for cubes in s.trim().split_terminator(',') {
+            let start = at + cubes.len() - cubes.trim_start().len();
+            at += cubes.len() + 1;
+
+            let cubes = cubes.trim();
+            let (count, color) = cubes
+                .split_once(' ')
+                .with_context(|| format!("Parsing '{cubes}' as set"))?;
+
+            let count: u32 = count
+                .parse()
+                .with_context(|| format!("Not a number: {}", count))?;
+
+            let color = color.trim();
+            ensure!(!color.is_empty(), "Missing the color in '{cubes}'");
+
+            if strict {
+                let column = line[..start].chars().count() + 1;
+                if let Some(first) = columns.insert(color, column) {
+                    bail!("Color '{color}' drawn twice in the same set, at columns {first} and {column}");
+                }
+            }
+
+            set += Set::from_iter([(color, count)]);
+        }
+        Ok(set)
+    }
+}
+
+impl<C: Into<String>> FromIterator<(C, u32)> for Set {
+    fn from_iter<I: IntoIterator<Item = (C, u32)>>(iter: I) -> Self {
+        Set(iter
+            .into_iter()
+            .map(|(color, count)| (color.into(), count))
+            .collect())
+    }
+}
+
+impl AddAssign for Set {
+    fn add_assign(&mut self, rhs: Self) {
+        for (color, count) in rhs.0 {
+            *self.0.entry(color).or_default() += count;
+        }
+    }
+}
+
+impl FromStr for Set {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Set::parse(s, 0..s.len(), false)
+    }
+}
+
+impl Display for Set {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut cubes = self.iter().filter(|(_, count)| *count > 0);
+
+        if let Some((color, count)) = cubes.next() {
+            write!(f, "{count} {color}")?;
+        }
+
+        for (color, count) in cubes {
+            write!(f, ", {count} {color}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Game of cubes drawn from a bag, parsed from a line like `Game 1: 3 blue, 4 red; 2 green`.
+#[derive(Debug)]
+pub struct Game {
+    pub id: u32,
+    pub sets: Vec<Set>,
+}
+
+impl Game {
+    /// Smallest set of cubes that every set of the game could have been drawn from.
+    pub fn max(&self) -> Set {
+        self.sets.iter().fold(Set::default(), Set::max)
+    }
+}
+
+impl Game {
+    /// Parses a line like `Game 1: 3 blue, 4 red; 2 green`.
+    ///
+    /// A colour drawn more than once in the same set has its counts added up, unless
+    /// `strict`, in which case that fails naming the columns it was drawn at.
+    pub fn parse(s: &str, strict: bool) -> Result<Game> {
+        let (name, rest) = s.split_once(':').context("Parsing game name")?;
+
+        let mut sets: Vec<Set> = Vec::with_capacity(3);
+        let mut at = name.len() + 1;
+
+        for unparsed_set in rest.split_terminator(';') {
+            let set = Set::parse(s, at..at + unparsed_set.len(), strict)
+                .with_context(|| format!("Parsing game subset: {unparsed_set}"))?;
+            at += unparsed_set.len() + 1;
+
+            sets.push(set);
+        }
+
+        let id: u32 = name
+            .trim()
+            .rsplit_once(' ')
+            .context("Parsing game id")?
+            .1
+            .parse()?;
+
+        Ok(Game { id, sets })
+    }
+}
+
+impl FromStr for Game {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Game::parse(s, false)
+    }
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Game { id, sets } = self;
+        write!(f, "Game {id}: ")?;
+
+        let mut sets = sets.iter();
+        if let Some(set) = sets.next() {
+            write!(f, "{set}")?;
+        }
+
+        for set in sets {
+            write!(f, "; {set}")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use day2::{parse_limit, parse_limits, part1, part2, Game, GameRecord, Set, LIMITS};
+
+fn record(lines: &[&str], bag: Set) -> GameRecord {
+    GameRecord {
+        games: lines.iter().map(|line| line.parse().unwrap()).collect(),
+        bag,
+    }
+}
+
+#[test]
+fn sets_accept_any_color() {
+    let set: Set = "3 purple, 4 red, 1 dark blue".parse().unwrap();
+
+    assert_eq!(set.count("purple"), 3);
+    assert_eq!(set.count("dark blue"), 1);
+    assert_eq!(set.count("green"), 0);
+    assert_eq!(set.to_string(), "1 dark blue, 3 purple, 4 red");
+}
+
+#[test]
+fn games_fit_in_the_bag_only_with_enough_cubes_of_every_color() {
+    let bag: Set = LIMITS.iter().copied().collect();
+    let record = record(
+        &[
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue",
+            "Game 2: 13 red",
+            "Game 3: 1 purple",
+        ],
+        bag,
+    );
+
+    assert_eq!(part1(&record), 1);
+}
+
+#[test]
+fn power_counts_every_color_drawn_in_the_record() {
+    let record = record(
+        &["Game 1: 3 purple, 2 red; 4 purple", "Game 2: 5 red"],
+        Set::default(),
+    );
+
+    assert_eq!(
+        record.colors().into_iter().collect::<Vec<_>>(),
+        ["purple", "red"]
+    );
+    assert_eq!(part2(&record), 8);
+}
+
+#[test]
+fn game_keeps_the_largest_count_of_every_color() {
+    let game: Game = "Game 7: 3 blue, 4 red; 1 red, 2 green, 6 blue"
+        .parse()
+        .unwrap();
+
+    assert_eq!(game.max().to_string(), "6 blue, 2 green, 4 red");
+}
+
+#[test]
+fn limits_are_read_from_color_count_lines() {
+    let bag = parse_limits("# Bigger bag\nred = 20\n\ngreen=13\n").unwrap();
+
+    assert_eq!(bag.iter().collect::<Vec<_>>(), [("green", 13), ("red", 20)]);
+    assert_eq!(parse_limit("blue=14").unwrap(), ("blue".to_string(), 14));
+
+    let error = parse_limits("red = 12\nblue\n").unwrap_err();
+    assert_eq!(error.to_string(), "Reading the limit on line 2");
+    assert_eq!(
+        error.root_cause().to_string(),
+        "Expected 'color = count', but got \"blue\""
+    );
+}
+
+#[test]
+fn lenient_games_add_up_a_color_drawn_twice() {
+    let game = Game::parse("Game 1: 3 red, 4 red; 2 blue", false).unwrap();
+
+    assert_eq!(game.sets[0].count("red"), 7);
+}
+
+#[test]
+fn strict_games_reject_a_color_drawn_twice_naming_the_columns() {
+    let error = Game::parse("Game 1: 1 blue; 2 grün, 3 red , 4 grün", true).unwrap_err();
+
+    assert_eq!(
+        error.root_cause().to_string(),
+        "Color 'grün' drawn twice in the same set, at columns 17 and 33"
+    );
+    assert!(Game::parse("Game 1: 3 red; 4 red", true).is_ok());
+}
@@ -0,0 +1,103 @@
+use std::process::Output;
+
+use aoc_common::test_support;
+
+const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../test/day2part1.txt");
+const EXPECTED: &str = "Day 2, part 1: 8\nDay 2, part 2: 2286\n";
+
+fn run(args: &[&str], stdin: Option<&[u8]>) -> Output {
+    test_support::run(env!("CARGO_BIN_EXE_day2"), args, stdin)
+}
+
+#[test]
+fn reads_piped_stdin_for_dash() {
+    let input = std::fs::read(FIXTURE).unwrap();
+    let output = run(&["-"], Some(&input));
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), EXPECTED);
+}
+
+#[test]
+fn reads_file_named_by_argument() {
+    let output = run(&[FIXTURE], None);
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), EXPECTED);
+}
+
+#[test]
+fn limits_replace_the_ones_of_the_default_bag() {
+    let output = run(&[FIXTURE, "--limit", "red=20", "--limit", "blue=15"], None);
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Day 2, part 1: 15\nDay 2, part 2: 2286\n"
+    );
+}
+
+fn failure(stdin: &[u8]) -> String {
+    let output = run(&["-", "--strict"], Some(stdin));
+
+    assert!(!output.status.success());
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn strict_mode_accepts_the_puzzle_example() {
+    let output = run(&[FIXTURE, "--strict"], None);
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), EXPECTED);
+}
+
+#[test]
+fn strict_mode_rejects_a_color_drawn_twice_in_a_set() {
+    let stderr = failure(b"Game 1: 3 red\nGame 2: 3 red, 4 red\n");
+
+    assert!(stderr.contains("Parsing the game on line 2"), "{stderr}");
+    assert!(
+        stderr.contains("Color 'red' drawn twice in the same set, at columns 9 and 16"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn strict_mode_rejects_repeated_and_skipped_game_ids() {
+    let stderr = failure(b"Game 1: 3 red\nGame 1: 4 red\n");
+    assert!(
+        stderr.contains("Checking the id of the game on line 2"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("Game 1 was already recorded on line 1"),
+        "{stderr}"
+    );
+
+    let stderr = failure(b"Game 1: 3 red\n\nGame 3: 4 red\n");
+    assert!(
+        stderr.contains("Checking the id of the game on line 3"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("Expected game 2, but got game 3"),
+        "{stderr}"
+    );
+}
@@ -0,0 +1,48 @@
+use std::hint::black_box;
+
+use aoc_common::test_support::Rng;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day3::scan;
+
+const SYMBOLS: [char; 6] = ['*', '#', '+', '$', '/', '='];
+
+/// Schematic of numbers of up to three digits, scattered symbols and mostly dots.
+fn generate(lines: usize, width: usize) -> Vec<String> {
+    let mut rng = Rng(0x2023_1203);
+
+    (0..lines)
+        .map(|_| {
+            let mut line = String::with_capacity(width);
+            while line.len() < width {
+                match rng.below(10) {
+                    0 | 1 => {
+                        let digits = (1 + rng.below(3)).min(width - line.len());
+                        for _ in 0..digits {
+                            line.push(char::from(b'0' + rng.below(10) as u8));
+                        }
+                    }
+                    2 => line.push(SYMBOLS[rng.below(SYMBOLS.len())]),
+                    _ => line.push('.'),
+                }
+
+                if line.len() < width {
+                    line.push('.');
+                }
+            }
+            line
+        })
+        .collect()
+}
+
+fn schematic(c: &mut Criterion) {
+    let schematic = generate(1_000, 140);
+    let bytes: usize = schematic.iter().map(|line| line.len() + 1).sum();
+
+    let mut group = c.benchmark_group("schematic");
+    group.throughput(Throughput::Bytes(bytes as u64));
+    group.bench_function("scan", |b| b.iter(|| scan(black_box(&schematic)).unwrap()));
+    group.finish();
+}
+
+criterion_group!(benches, schematic);
+criterion_main!(benches);
@@ -0,0 +1,237 @@
+//! Day 3: Gear Ratios
+
+use crate::Char::{Blank, Digit, Symbol};
+use anyhow::{bail, Result};
+use aoc_common::{Input, Solution};
+use itertools::izip;
+use log::trace;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+
+    type Parsed = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: Input) -> Result<Self::Parsed> {
+        input.lines().collect()
+    }
+
+    fn part1(schematic: &Self::Parsed) -> Result<Self::Part1> {
+        part1(schematic)
+    }
+
+    fn part2(schematic: &Self::Parsed) -> Result<Self::Part2> {
+        part2(schematic)
+    }
+}
+
+/// Sum of the numbers adjacent to a symbol.
+pub fn part1(schematic: &[String]) -> Result<u32> {
+    let (part1, _) = scan(schematic)?;
+    Ok(part1)
+}
+
+/// Sum of the gear ratios, the products of the two numbers adjacent to a `*`.
+pub fn part2(schematic: &[String]) -> Result<u32> {
+    let (_, part2) = scan(schematic)?;
+    Ok(part2)
+}
+
+enum Char {
+    Digit,
+    Symbol(char),
+    Blank,
+}
+
+impl From<char> for Char {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Blank,
+            _ if value.is_ascii_digit() => Digit,
+            _ => Symbol(value),
+        }
+    }
+}
+
+/// Scans the schematic three lines at a time, summing up the part numbers
+/// and the gear ratios.
+pub fn scan(schematic: &[String]) -> Result<(u32, u32)> {
+    let line_length = schematic
+        .first()
+        .map(|s| s.chars().count())
+        .unwrap_or_default();
+    let blank_line = ".".repeat(line_length);
+
+    let mut lines: Vec<&str> = Vec::with_capacity(schematic.len() + 2);
+    lines.push(&blank_line);
+    lines.extend(schematic.iter().map(String::as_str));
+    lines.push(&blank_line);
+
+    let mut part1 = 0u32;
+    let mut part2 = 0u32;
+    for window in lines.windows(3) {
+        let &[previous, current, next] = window else {
+            bail!("Expected window of three lines")
+        };
+
+        let (part_numbers, gear_ratios) = scan_window([previous, current, next]);
+        part1 += part_numbers;
+        part2 += gear_ratios;
+    }
+
+    Ok((part1, part2))
+}
+
+/// Sums up the part numbers and the gear ratios next to the symbols
+/// on the middle line of a window of three lines.
+pub fn scan_window(window: [&str; 3]) -> (u32, u32) {
+    let [previous, current, next] = window;
+    let line_length = current.chars().count();
+
+    let mut part1 = 0u32;
+    let mut part2 = 0u32;
+
+    trace!("\n{}", "=".repeat(line_length + 2));
+    if !current.contains(|c: char| !c.is_ascii_digit() && c != '.') {
+        trace!("Skipping:\n {} \n→{}←\n {} ", previous, current, next);
+        return (0, 0);
+    }
+
+    let slice = izip!(
+        previous.char_indices(),
+        current.char_indices(),
+        next.char_indices()
+    );
+    let sl = slice.map(|((i, a), (_, b), (_, c))| (i, [a, b, c]));
+
+    let mut start: [Option<usize>; 3] = [None; 3];
+    let mut symbol: Option<usize> = None;
+    let mut gear: Option<usize> = None;
+
+    let mut numbers: Vec<u32> = Vec::with_capacity(6);
+
+    for (i, ccc) in sl {
+        trace!("{} ↓", " ".repeat(i));
+        let i0 = start[0].unwrap_or(i);
+        trace!(
+            "{}[{}]{}",
+            previous.get(..i0).unwrap_or_default(),
+            previous.get(i0..(i + 1)).unwrap_or_default(),
+            previous.get((i + 1)..).unwrap_or_default()
+        );
+        let i0 = start[1].unwrap_or(i);
+        trace!(
+            "{}[{}]{}",
+            current.get(..i0).unwrap_or_default(),
+            current.get(i0..(i + 1)).unwrap_or_default(),
+            current.get((i + 1)..).unwrap_or_default()
+        );
+        let i0 = start[2].unwrap_or(i);
+        trace!(
+            "{}[{}]{}",
+            next.get(..i0).unwrap_or_default(),
+            next.get(i0..(i + 1)).unwrap_or_default(),
+            next.get((i + 1)..).unwrap_or_default()
+        );
+        trace!("{} ↑", " ".repeat(i));
+
+        let mut res: [(Option<u32>, bool, bool); 3] = Default::default();
+        for n in 0..3 {
+            let (number, s, g) = match Char::from(ccc[n]) {
+                Digit => {
+                    if start[n].is_none() {
+                        start[n] = Some(i);
+                    }
+                    if i + 1 == line_length {
+                        let num = parse_number(window[n], start[n], i + 1);
+                        (num, false, false)
+                    } else {
+                        (None, false, false)
+                    }
+                }
+                Symbol(c) => {
+                    let num = parse_number(window[n], start[n], i);
+                    (num, true, c == '*')
+                }
+                Blank => {
+                    let num = parse_number(window[n], start[n], i);
+                    (num, false, false)
+                }
+            };
+
+            trace!(
+                "{} number: {number:?}{}{}",
+                ["⎧", "⎬", "⎩"][n],
+                if s { "; symbol" } else { "" },
+                if g { "; gear" } else { "" }
+            );
+
+            res[n] = (number, s, g);
+        }
+
+        let (_, symbol1, gear1) = res[1];
+        if symbol1 {
+            symbol = Some(i);
+        }
+
+        if gear1 {
+            gear = Some(i);
+        }
+
+        let numbers_with_start = izip!(res.map(|(num, _, _)| num), start)
+            .filter_map(|(num, start)| num.and_then(|num| start.map(|start| (start, num))))
+            .collect::<Vec<(usize, u32)>>();
+
+        if let Some(symbol) = symbol {
+            for (start, num) in numbers_with_start.iter() {
+                trace!("❯❯ start: {start}; symbol: {symbol}, number: {num}");
+                if *start <= symbol || start.abs_diff(symbol) < 2 {
+                    part1 += *num;
+                }
+            }
+        }
+
+        if let Some(gear) = gear {
+            for (start, num) in numbers_with_start.iter() {
+                trace!("❯❯ start: {start}; gear: {gear}, number: {num}");
+                if *start <= gear || start.abs_diff(gear) < 2 {
+                    numbers.push(*num);
+                }
+            }
+        }
+
+        trace!("❯ symbol: {symbol:?}; gear: {gear:?}, numbers: {numbers:?}");
+        trace!("❯ part1 = {part1}");
+        trace!("❯ part2 = {part2}");
+        if (['.', '.', '.'] == ccc || i + 1 == line_length) && !numbers.is_empty() {
+            if numbers.len() > 1 {
+                let gear_ratio: u32 = numbers.iter().product();
+                trace!("❯ part2: {part2} + {gear_ratio} = {}", part2 + gear_ratio);
+                part2 += gear_ratio;
+            }
+            numbers.clear();
+        }
+
+        for n in 0..3 {
+            if res[n].0.is_some() {
+                start[n] = None
+            }
+        }
+
+        trace!("\n{}\n", "~".repeat(line_length + 2));
+    }
+    trace!("{}\n", "=".repeat(line_length + 2));
+
+    (part1, part2)
+}
+
+fn parse_number(line: &str, start: Option<usize>, end: usize) -> Option<u32> {
+    let num = start
+        .and_then(|it| line.get(it..end))
+        .and_then(|s| s.parse::<u32>().ok());
+    num
+}
@@ -0,0 +1,49 @@
+use std::hint::black_box;
+
+use aoc_common::test_support::Rng;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day4::{part2, Card};
+
+/// Cards with ten winning numbers and twenty-five numbers you have, all below 100.
+fn generate(cards: usize) -> Vec<String> {
+    let mut rng = Rng(0x2023_1204);
+    let mut numbers = |count: usize| {
+        (0..count)
+            .map(|_| format!("{:>2}", 1 + rng.below(99)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    (1..=cards)
+        .map(|id| format!("Card {id}: {} | {}", numbers(10), numbers(25)))
+        .collect()
+}
+
+fn cards(c: &mut Criterion) {
+    let lines = generate(10_000);
+    let cards: Vec<Card> = lines.iter().map(|line| line.parse().unwrap()).collect();
+
+    let mut group = c.benchmark_group("cards");
+    group.throughput(Throughput::Elements(cards.len() as u64));
+    group.bench_function("Card::from_str", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .map(|line| black_box(line).parse().unwrap())
+                .collect::<Vec<Card>>()
+        })
+    });
+    group.bench_function("Card::matching_numbers", |b| {
+        b.iter(|| {
+            black_box(&cards)
+                .iter()
+                .map(Card::matching_numbers)
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&cards))));
+    group.finish();
+}
+
+criterion_group!(benches, cards);
+criterion_main!(benches);
@@ -0,0 +1,126 @@
+//! Day 4: Scratchcards
+
+use std::cmp::min;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use anyhow::{bail, Result};
+use aoc_common::{Input, Solution};
+use log::debug;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    type Parsed = Vec<Card>;
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn parse(input: Input) -> Result<Self::Parsed> {
+        let mut cards = Vec::new();
+        for line in input.lines() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+
+            cards.push(line.parse()?);
+        }
+
+        Ok(cards)
+    }
+
+    fn part1(cards: &Self::Parsed) -> Result<Self::Part1> {
+        Ok(part1(cards))
+    }
+
+    fn part2(cards: &Self::Parsed) -> Result<Self::Part2> {
+        Ok(part2(cards))
+    }
+}
+
+/// Scratchcard parsed from a line like `Card 1: 41 48 83 | 83 86  6`.
+#[derive(Debug)]
+pub struct Card {
+    pub name: String,
+    pub winning_numbers: HashSet<u32>,
+    pub numbers_you_have: HashSet<u32>,
+}
+
+impl Card {
+    /// Number of the numbers you have that are among the winning numbers.
+    pub fn matching_numbers(&self) -> usize {
+        let matching_numbers = self
+            .winning_numbers
+            .intersection(&self.numbers_you_have)
+            .count();
+
+        let name = &self.name;
+        debug!("❯ {name}: Found {matching_numbers} numbers matching the winning numbers");
+
+        matching_numbers
+    }
+
+    /// Points the card is worth: one for the first match, doubled for each match after that.
+    pub fn score(&self) -> u32 {
+        match self.matching_numbers() {
+            0 => 0,
+            matching_numbers => 2u32.pow(matching_numbers as u32 - 1),
+        }
+    }
+}
+
+impl FromStr for Card {
+    type Err = anyhow::Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let Some((name, line)) = line.split_once(": ") else {
+            bail!("Parse card name");
+        };
+
+        let Some((winning_numbers, numbers_you_have)) = line.split_once(" | ") else {
+            bail!("Winnings vs numbers I have");
+        };
+
+        let winning_numbers: HashSet<u32> = winning_numbers
+            .split_whitespace()
+            .filter_map(|s| s.parse::<u32>().ok())
+            .collect();
+
+        let numbers_you_have: HashSet<u32> = numbers_you_have
+            .split_whitespace()
+            .filter_map(|s| s.parse::<u32>().ok())
+            .collect();
+
+        Ok(Card {
+            name: name.to_string(),
+            winning_numbers,
+            numbers_you_have,
+        })
+    }
+}
+
+/// Total points of all the scratchcards.
+pub fn part1(cards: &[Card]) -> u32 {
+    cards.iter().map(Card::score).sum()
+}
+
+/// Number of scratchcards you end up with, counting the copies won by the matching numbers.
+pub fn part2(cards: &[Card]) -> usize {
+    let mut multipliers: Vec<usize> = vec![1; cards.len()];
+
+    for (index, card) in cards.iter().enumerate() {
+        let multiplier = multipliers[index];
+        let matching_numbers = card.matching_numbers();
+
+        let start = index + 1;
+        let end = min(start + matching_numbers, cards.len());
+        for m in &mut multipliers[start..end] {
+            *m += multiplier;
+        }
+    }
+
+    multipliers.iter().sum()
+}
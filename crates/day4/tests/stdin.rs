@@ -0,0 +1,35 @@
+use std::process::Output;
+
+use aoc_common::test_support;
+
+const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../test/day4.txt");
+const EXPECTED: &str = "Day 4, part 1: 13\nDay 4, part 2: 30\n";
+
+fn run(args: &[&str], stdin: Option<&[u8]>) -> Output {
+    test_support::run(env!("CARGO_BIN_EXE_day4"), args, stdin)
+}
+
+#[test]
+fn reads_piped_stdin_for_dash() {
+    let input = std::fs::read(FIXTURE).unwrap();
+    let output = run(&["-"], Some(&input));
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), EXPECTED);
+}
+
+#[test]
+fn reads_file_named_by_argument() {
+    let output = run(&[FIXTURE], None);
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), EXPECTED);
+}
@@ -0,0 +1,67 @@
+use std::hint::black_box;
+
+use aoc_common::test_support::Rng;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day5::{CategoryMap, RangeMapEntry};
+use intervals::RangeSet;
+
+/// Map lines of `entries` adjacent source ranges, shuffled onto destination ranges.
+fn generate_map(rng: &mut Rng, entries: usize) -> Vec<String> {
+    let mut source = 0;
+    (0..entries)
+        .map(|_| {
+            let length = 1_000_000 + rng.below(100_000_000);
+            let destination = rng.below(4_000_000_000);
+            let line = format!("{destination} {source} {length}");
+            source += length;
+            line
+        })
+        .collect()
+}
+
+fn range_map(c: &mut Criterion) {
+    let mut rng = Rng(0x2023_1205);
+    let map = generate_map(&mut rng, 50);
+    let entries: Vec<RangeMapEntry> = map.iter().map(|line| line.parse().unwrap()).collect();
+    let end = entries
+        .last()
+        .map(|e| e.source_range_start + e.range_length)
+        .unwrap();
+
+    let category_map = CategoryMap::new(
+        "seed",
+        "soil",
+        map.iter().map(|line| line.parse().unwrap()).collect(),
+    );
+
+    let mut keys: Vec<usize> = (0..100_000).map(|_| rng.below(end)).collect();
+    keys.sort();
+    let ranges: RangeSet<usize> = keys
+        .chunks(2)
+        .map(|pair| pair[0]..pair[0] + 1_000)
+        .collect();
+
+    let mut group = c.benchmark_group("range_map");
+    group.throughput(Throughput::Elements(keys.len() as u64));
+    group.bench_function("RangeMapEntry::get", |b| {
+        b.iter(|| {
+            keys.iter()
+                .filter_map(|&key| entries.iter().find_map(|entry| entry.get(black_box(key))))
+                .count()
+        })
+    });
+    group.bench_function("CategoryMap::get", |b| {
+        b.iter(|| {
+            keys.iter()
+                .map(|&key| category_map.get(black_box(key)))
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("CategoryMap::map_ranges", |b| {
+        b.iter(|| category_map.map_ranges(black_box(&ranges)).min())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, range_map);
+criterion_main!(benches);
@@ -0,0 +1,107 @@
+//! Draws the maps of the almanac as Graphviz DOT graphs.
+
+use std::fmt::Write as _;
+use std::ops::Range;
+
+use intervals::RangeSet;
+
+use crate::{Almanac, CategoryMap, RangeMapEntry};
+
+/// Graph with a node for every category named in the section headers of the `almanac`
+/// and an edge for every map, labelled with its number of entries and how much of the
+/// source numbers they cover.
+pub fn categories(almanac: &Almanac) -> String {
+    let mut dot = String::from("digraph almanac {\n    rankdir=LR;\n    node [shape=box];\n");
+
+    for map in &almanac.maps {
+        let covered: RangeSet<usize> = map
+            .entries()
+            .iter()
+            .map(RangeMapEntry::source_range)
+            .collect();
+        let count: usize = covered.iter().map(|range| range.len()).sum();
+
+        let mut label = format!("{} entries", map.entries().len());
+        if let (Some(first), Some(last)) = (covered.ranges().first(), covered.ranges().last()) {
+            let span = last.end - first.start;
+            let _ = write!(
+                label,
+                "\\n{count} numbers covered\\n{:.1}% of {}..{}",
+                count as f64 / span as f64 * 100.0,
+                first.start,
+                last.end
+            );
+        }
+
+        let _ = writeln!(
+            dot,
+            "    {} -> {} [label={}];",
+            quote(&map.source),
+            quote(&map.destination),
+            quote(&label)
+        );
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Graph of the parts the range of `seeds` splits into on the way through the `chain` of maps,
+/// with a cluster of nodes for every category and an edge from every part to what it maps onto.
+pub fn splits(chain: &[&CategoryMap], seeds: Range<usize>) -> String {
+    let mut dot = String::from("digraph splits {\n    rankdir=LR;\n    node [shape=box];\n");
+
+    let first = chain.first().map_or("seed", |map| map.source.as_str());
+    let categories = std::iter::once(first).chain(chain.iter().map(|map| map.destination.as_str()));
+
+    // Parts of the current category, with the id of their node.
+    let mut parts = vec![("0:0".to_string(), seeds)];
+    for (level, category) in categories.enumerate() {
+        let _ = writeln!(
+            dot,
+            "    subgraph cluster_{level} {{\n        label={};",
+            quote(category)
+        );
+        for (id, range) in &parts {
+            let _ = writeln!(dot, "        {} [label=\"{range:?}\"];", quote(id));
+        }
+        dot.push_str("    }\n");
+
+        let Some(map) = chain.get(level) else {
+            break;
+        };
+
+        let mut next = Vec::new();
+        for (id, range) in &parts {
+            for (source, destination) in map.split(range.clone()) {
+                let next_id = format!("{}:{}", level + 1, next.len());
+                let label = match map.entry(source.start) {
+                    Some(entry) => format!("{source:?} (∆{})", delta(entry)),
+                    None => format!("{source:?} (identity)"),
+                };
+
+                let _ = writeln!(
+                    dot,
+                    "    {} -> {} [label={}];",
+                    quote(id),
+                    quote(&next_id),
+                    quote(&label)
+                );
+                next.push((next_id, destination));
+            }
+        }
+        parts = next;
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+fn delta(entry: &RangeMapEntry) -> i128 {
+    entry.destination_range_start as i128 - entry.source_range_start as i128
+}
+
+/// DOT string literal of `s`, with the quotes in it escaped.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\\\""))
+}
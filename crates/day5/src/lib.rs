@@ -0,0 +1,754 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+use std::collections::HashSet;
+use std::fmt::{Debug, Formatter};
+use std::ops::Range;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Error, Result};
+use aoc_common::{Input, Solution};
+use intervals::{IntervalMap, RangeSet};
+use itertools::Itertools;
+
+pub mod dot;
+pub mod trace;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    type Parsed = Almanac;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: Input) -> Result<Self::Parsed> {
+        let lines = input.lines().collect::<Result<Vec<String>>>()?;
+        lines.join("\n").parse()
+    }
+
+    fn part1(almanac: &Self::Parsed) -> Result<Self::Part1> {
+        part1(almanac)
+    }
+
+    fn part2(almanac: &Self::Parsed) -> Result<Self::Part2> {
+        part2(almanac)
+    }
+}
+
+/// Category the almanac starts from.
+pub const SEED: &str = "seed";
+
+/// Category the almanac ends at.
+pub const LOCATION: &str = "location";
+
+/// Line of an almanac map, mapping a range of source numbers onto a range of destination numbers.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct RangeMapEntry {
+    pub source_range_start: usize,
+    pub destination_range_start: usize,
+    pub range_length: usize,
+}
+
+impl RangeMapEntry {
+    /// Entry with its numbers in the order of a line of the almanac, like `50 98 2`.
+    pub fn new(
+        destination_range_start: usize,
+        source_range_start: usize,
+        range_length: usize,
+    ) -> RangeMapEntry {
+        RangeMapEntry {
+            source_range_start,
+            destination_range_start,
+            range_length,
+        }
+    }
+
+    /// Source numbers the entry maps, cut short at `usize::MAX`.
+    pub fn source_range(&self) -> Range<usize> {
+        self.source_range_start..self.source_range_start.saturating_add(self.range_length)
+    }
+
+    /// Destination numbers the entry maps onto, cut short at `usize::MAX`.
+    pub fn destination_range(&self) -> Range<usize> {
+        self.destination_range_start
+            ..self
+                .destination_range_start
+                .saturating_add(self.range_length)
+    }
+
+    /// Destination number of the `key`, if it is within the source range.
+    ///
+    /// Like the ranges, the destination number is cut short at `usize::MAX`.
+    pub fn get(&self, key: usize) -> Option<usize> {
+        self.source_range().contains(&key).then(|| {
+            let delta = key - self.source_range_start;
+            self.destination_range_start.saturating_add(delta)
+        })
+    }
+
+    /// Source number that maps onto the `value`, if it is within the destination range and
+    /// the source range has not been cut short before it.
+    pub fn invert(&self, value: usize) -> Option<usize> {
+        if !self.destination_range().contains(&value) {
+            return None;
+        }
+        self.source_range_start
+            .checked_add(value - self.destination_range_start)
+            .filter(|source| self.source_range().contains(source))
+    }
+}
+
+impl FromStr for RangeMapEntry {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (destination_range_start, source_range_start, range_length) = s
+            .split_ascii_whitespace()
+            .filter_map(|s| s.parse::<usize>().ok())
+            .collect_tuple()
+            .with_context(|| format!("Expected three numbers, but got \"{s}\""))?;
+
+        Ok(RangeMapEntry::new(
+            destination_range_start,
+            source_range_start,
+            range_length,
+        ))
+    }
+}
+
+impl Debug for RangeMapEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let source = self.source_range();
+        let destination = self.destination_range();
+        let delta = (self.destination_range_start as i128) - (self.source_range_start as i128);
+
+        write!(f, "{source:?} → {destination:?} (∆{delta})")
+    }
+}
+
+/// Accessor of the source or destination range of a [`RangeMapEntry`].
+type RangeOf = fn(&RangeMapEntry) -> Range<usize>;
+
+/// Map from the numbers of one category onto the numbers of the next, like the
+/// `seed-to-soil map:` section of the almanac.
+#[derive(Clone)]
+pub struct CategoryMap {
+    pub source: String,
+    pub destination: String,
+    /// Entries of the map as given, sorted by their source ranges.
+    entries: Vec<RangeMapEntry>,
+    /// Position of each of the sorted entries in the order they were given in, from 0.
+    positions: Vec<usize>,
+    /// Numbers of the lines the entries were read from, when the map was parsed from an almanac.
+    lines: Vec<usize>,
+    /// Entry that applies to each source range, the one that comes first in the input where
+    /// entries overlap.
+    index: IntervalMap<usize, RangeMapEntry>,
+}
+
+impl CategoryMap {
+    /// Map of the `entries`. Where their source ranges overlap, which
+    /// [`problems`](CategoryMap::problems) reports, the entry that comes first applies.
+    pub fn new(source: &str, destination: &str, entries: Vec<RangeMapEntry>) -> CategoryMap {
+        CategoryMap::with_lines(source, destination, entries, Vec::new())
+    }
+
+    /// Map of `entries` read from the `lines` with these numbers.
+    fn with_lines(
+        source: &str,
+        destination: &str,
+        entries: Vec<RangeMapEntry>,
+        lines: Vec<usize>,
+    ) -> CategoryMap {
+        // Later ranges override earlier ones, so the entry that comes first wins where they
+        // overlap.
+        let index = entries
+            .iter()
+            .rev()
+            .map(|entry| (entry.source_range(), *entry))
+            .collect();
+
+        let positions: Vec<usize> = (0..entries.len())
+            .sorted_by_key(|&index| entries[index].source_range_start)
+            .collect();
+        let lines = positions
+            .iter()
+            .filter_map(|&index| lines.get(index).copied())
+            .collect();
+        let entries = positions.iter().map(|&index| entries[index]).collect();
+
+        CategoryMap {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            entries,
+            positions,
+            lines,
+            index,
+        }
+    }
+
+    /// Entries of the map, sorted by their source ranges.
+    pub fn entries(&self) -> &[RangeMapEntry] {
+        &self.entries
+    }
+
+    /// Problems that make the map ambiguous or impossible to apply, which the puzzle input
+    /// never has but nothing else rejects: ranges whose end overflows, source ranges that
+    /// overlap, and destination ranges that overlap, so that two numbers map onto one.
+    ///
+    /// Gaps between the entries are fine, the numbers in them map onto themselves.
+    pub fn problems(&self) -> Vec<String> {
+        let name = format!("{}-to-{} map", self.source, self.destination);
+        let mut problems = Vec::new();
+
+        let mut valid = Vec::with_capacity(self.entries.len());
+        for (index, entry) in self.entries.iter().enumerate() {
+            let overflows = [
+                ("source", entry.source_range_start),
+                ("destination", entry.destination_range_start),
+            ]
+            .into_iter()
+            .filter(|(_, start)| start.checked_add(entry.range_length).is_none())
+            .map(|(kind, start)| {
+                format!(
+                    "{}: {kind} range {start} + {} overflows",
+                    self.locate(&[index]),
+                    entry.range_length
+                )
+            })
+            .collect::<Vec<_>>();
+
+            if overflows.is_empty() {
+                valid.push(index);
+            } else {
+                problems.extend(
+                    overflows
+                        .into_iter()
+                        .map(|problem| format!("{name}, {problem}")),
+                );
+            }
+        }
+
+        let ranges: [(&str, RangeOf); 2] = [
+            ("source", RangeMapEntry::source_range),
+            ("destination", RangeMapEntry::destination_range),
+        ];
+        for (kind, range) in ranges {
+            let mut sorted = valid.clone();
+            sorted.sort_by_key(|&index| range(&self.entries[index]).start);
+
+            // Entry reaching furthest so far, which any later entry overlapping one before it overlaps too.
+            let mut furthest: Option<usize> = None;
+            for index in sorted {
+                let current = range(&self.entries[index]);
+                if current.is_empty() {
+                    continue;
+                }
+
+                if let Some(before) = furthest {
+                    let previous = range(&self.entries[before]);
+                    if current.start < previous.end {
+                        let mut pair = [before, index];
+                        pair.sort_by_key(|&index| self.lines.get(index).copied().unwrap_or(index));
+                        let [first, second] = pair.map(|index| range(&self.entries[index]));
+                        problems.push(format!(
+                            "{name}, {}: {kind} ranges {first:?} and {second:?} overlap",
+                            self.locate(&pair)
+                        ));
+                    }
+                    if current.end <= previous.end {
+                        continue;
+                    }
+                }
+                furthest = Some(index);
+            }
+        }
+
+        problems
+    }
+
+    /// Where the entries at the `indices` came from, like `lines 4 and 5`, or like
+    /// `entries 1 and 2`, counted in the order they were given in, when the map was not
+    /// parsed from an almanac.
+    fn locate(&self, indices: &[usize]) -> String {
+        let (kind, numbers): (&str, Vec<usize>) = if self.lines.len() == self.entries.len() {
+            (
+                "line",
+                indices.iter().map(|&index| self.lines[index]).collect(),
+            )
+        } else {
+            (
+                "entry",
+                indices
+                    .iter()
+                    .map(|&index| self.positions[index] + 1)
+                    .collect(),
+            )
+        };
+
+        match numbers.as_slice() {
+            [number] => format!("{kind} {number}"),
+            numbers => {
+                let plural = if kind == "entry" { "entries" } else { "lines" };
+                format!("{plural} {}", numbers.iter().join(" and "))
+            }
+        }
+    }
+
+    /// Entry whose source range contains the `key`, if any.
+    pub fn entry(&self, key: usize) -> Option<&RangeMapEntry> {
+        self.index.get(&key)
+    }
+
+    /// Destination number of the `key`, which is the key itself when no entry maps it.
+    pub fn get(&self, key: usize) -> usize {
+        self.entry(key)
+            .and_then(|entry| entry.get(key))
+            .unwrap_or(key)
+    }
+
+    /// Maps the `ranges` of source numbers onto ranges of destination numbers.
+    ///
+    /// A range is split wherever it crosses the boundary of an entry, and the parts of it
+    /// that no entry covers map onto themselves.
+    pub fn map_ranges(&self, ranges: &RangeSet<usize>) -> RangeSet<usize> {
+        ranges
+            .iter()
+            .flat_map(|range| self.split(range.clone()))
+            .map(|(_, destination)| destination)
+            .collect()
+    }
+
+    /// Source numbers that map onto the `value`.
+    ///
+    /// There may be none, when an entry maps another number onto the value and the value
+    /// itself elsewhere, or several, when the destination ranges overlap.
+    pub fn invert(&self, value: usize) -> Vec<usize> {
+        let mut sources: Vec<usize> = self
+            .index
+            .iter()
+            .filter_map(|(range, entry)| {
+                entry.invert(value).filter(|source| range.contains(source))
+            })
+            .collect();
+
+        if self.entry(value).is_none() {
+            sources.push(value);
+        }
+
+        sources.sort();
+        sources
+    }
+
+    /// Source numbers that map into any of the `ranges` of destination numbers.
+    pub fn preimage(&self, ranges: &RangeSet<usize>) -> RangeSet<usize> {
+        let mut sources = RangeSet::new();
+
+        for (source, entry) in self.index.iter() {
+            let delta = source.start - entry.source_range_start;
+            let start = entry.destination_range_start.saturating_add(delta);
+            let destination: RangeSet<usize> =
+                std::iter::once(start..start.saturating_add(source.len())).collect();
+
+            sources.extend(destination.intersection(ranges).iter().map(|part| {
+                let start = source.start + (part.start - start);
+                start..start + part.len()
+            }));
+        }
+
+        // Numbers no entry covers map onto themselves.
+        let covered: RangeSet<usize> = self
+            .index
+            .iter()
+            .map(|(source, _)| source.clone())
+            .collect();
+        sources.union(&ranges.difference(&covered))
+    }
+
+    /// Splits the `range` of source numbers at the boundaries of the entries, pairing every
+    /// part with the destination numbers it maps onto.
+    ///
+    /// Like the [destination ranges](RangeMapEntry::destination_range) of the entries, the
+    /// destination numbers are cut short at `usize::MAX`.
+    pub fn split(&self, range: Range<usize>) -> Vec<(Range<usize>, Range<usize>)> {
+        self.index
+            .split(range)
+            .into_iter()
+            .map(|(part, entry)| {
+                let start = entry.map_or(part.start, |entry| {
+                    let delta = part.start - entry.source_range_start;
+                    entry.destination_range_start.saturating_add(delta)
+                });
+                (part.clone(), start..start.saturating_add(part.len()))
+            })
+            .collect()
+    }
+
+    /// Single map doing what this map and then the `next` one do, from the source category
+    /// of this map to the destination category of the `next`.
+    ///
+    /// Every source range is split wherever its destination crosses the boundary of an entry
+    /// of the `next` map, so the composed map has an entry for every piece with an offset of
+    /// its own. Pieces that end up where they started are left to the identity.
+    pub fn compose(&self, next: &CategoryMap) -> CategoryMap {
+        let mut entries: Vec<RangeMapEntry> = Vec::new();
+
+        for (source, middle) in self.split(0..usize::MAX) {
+            for (part, destination) in next.split(middle.clone()) {
+                let source_range_start = source.start + (part.start - middle.start);
+                if source_range_start == destination.start {
+                    continue;
+                }
+
+                let entry =
+                    RangeMapEntry::new(destination.start, source_range_start, destination.len());
+
+                match entries.last_mut() {
+                    Some(last)
+                        if last.source_range().end == entry.source_range_start
+                            && last.destination_range().end == entry.destination_range_start =>
+                    {
+                        last.range_length += entry.range_length;
+                    }
+                    _ => entries.push(entry),
+                }
+            }
+        }
+
+        CategoryMap::new(&self.source, &next.destination, entries)
+    }
+}
+
+impl Debug for CategoryMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-to-{} ", self.source, self.destination)?;
+        f.debug_list().entries(&self.entries).finish()
+    }
+}
+
+/// Seeds to plant and the maps between the categories of the almanac, in the order they
+/// appear in the input.
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    pub maps: Vec<CategoryMap>,
+    /// Line the seeds were read from.
+    seeds_line: usize,
+}
+
+impl Almanac {
+    /// Map from the `source` category onto the `destination` category.
+    pub fn map(&self, source: &str, destination: &str) -> Option<&CategoryMap> {
+        self.maps
+            .iter()
+            .find(|map| map.source == source && map.destination == destination)
+    }
+
+    /// Maps to apply one after the other to get from the `from` category to the `to` category.
+    ///
+    /// The order of the sections in the input does not matter, and maps off the way are ignored,
+    /// but there has to be exactly one way between the two categories.
+    pub fn chain(&self, from: &str, to: &str) -> Result<Vec<&CategoryMap>> {
+        let mut paths = Vec::new();
+        self.find_paths(from, to, &mut HashSet::new(), &mut Vec::new(), &mut paths);
+
+        match paths.len() {
+            0 => {
+                let reachable = self.reachable(from);
+                bail!(
+                    "No way from the {from} category to the {to} category; \
+                     the maps only lead from {from} to {}",
+                    reachable.join(", ")
+                )
+            }
+            1 => Ok(paths.remove(0)),
+            _ => {
+                let ways = paths
+                    .iter()
+                    .map(|path| {
+                        path.iter()
+                            .map(|map| map.destination.as_str())
+                            .collect::<Vec<_>>()
+                            .join(" → ")
+                    })
+                    .join(" or ");
+                bail!("Ambiguous way from the {from} category to the {to} category: {ways}")
+            }
+        }
+    }
+
+    fn find_paths<'a>(
+        &'a self,
+        from: &str,
+        to: &str,
+        visited: &mut HashSet<&'a str>,
+        path: &mut Vec<&'a CategoryMap>,
+        paths: &mut Vec<Vec<&'a CategoryMap>>,
+    ) {
+        if from == to {
+            paths.push(path.clone());
+            return;
+        }
+
+        for map in self.maps.iter().filter(|map| map.source == from) {
+            if !visited.insert(&map.destination) {
+                continue;
+            }
+
+            path.push(map);
+            self.find_paths(&map.destination, to, visited, path, paths);
+            path.pop();
+            visited.remove(map.destination.as_str());
+        }
+    }
+
+    /// Categories the maps lead to from the `from` category.
+    fn reachable<'a>(&'a self, from: &'a str) -> Vec<&'a str> {
+        let mut reachable = vec![from];
+        let mut index = 0;
+        while let Some(&category) = reachable.get(index) {
+            for map in self.maps.iter().filter(|map| map.source == category) {
+                if !reachable.contains(&map.destination.as_str()) {
+                    reachable.push(&map.destination);
+                }
+            }
+            index += 1;
+        }
+
+        reachable.remove(0);
+        reachable
+    }
+
+    /// Single map from the `from` category to the `to` category, composed of the [`chain`]
+    /// of maps between them.
+    ///
+    /// [`chain`]: Almanac::chain
+    pub fn compose(&self, from: &str, to: &str) -> Result<CategoryMap> {
+        let chain = self.chain(from, to)?;
+        let Some((first, rest)) = chain.split_first() else {
+            bail!("No maps to compose from the {from} category to itself");
+        };
+
+        Ok(rest
+            .iter()
+            .fold((*first).clone(), |composed, map| composed.compose(map)))
+    }
+
+    /// Numbers of the `from` category that map into any of the `ranges` of numbers of the
+    /// `to` category, going back through the [`chain`] of maps between them.
+    ///
+    /// [`chain`]: Almanac::chain
+    pub fn preimage(
+        &self,
+        from: &str,
+        to: &str,
+        ranges: &RangeSet<usize>,
+    ) -> Result<RangeSet<usize>> {
+        let chain = self.chain(from, to)?;
+
+        Ok(chain
+            .iter()
+            .rev()
+            .fold(ranges.clone(), |ranges, map| map.preimage(&ranges)))
+    }
+
+    /// Checks the ranges of seeds for overflows and every map for the
+    /// [problems](CategoryMap::problems) that make it ambiguous or impossible to apply,
+    /// and reports all of them at once.
+    pub fn validate(&self) -> Result<()> {
+        let problems: Vec<String> = self
+            .seed_problems()
+            .into_iter()
+            .chain(self.maps.iter().flat_map(CategoryMap::problems))
+            .collect();
+        if !problems.is_empty() {
+            bail!(
+                "{} problem(s) in the almanac:\n  {}",
+                problems.len(),
+                problems.join("\n  ")
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Seeds read as pairs of the start and the length of a range of seeds.
+    pub fn seed_ranges(&self) -> Result<RangeSet<usize>> {
+        if !self.seeds.len().is_multiple_of(2) {
+            bail!(
+                "Expected pairs of seed range start and length, but got {} numbers",
+                self.seeds.len()
+            );
+        }
+
+        self.seeds
+            .chunks(2)
+            .map(|pair| {
+                let end = pair[0].checked_add(pair[1]).with_context(|| {
+                    format!(
+                        "Seed range {} + {} on line {} overflows",
+                        pair[0], pair[1], self.seeds_line
+                    )
+                })?;
+                Ok(pair[0]..end)
+            })
+            .collect()
+    }
+
+    /// Ranges of seeds whose start and length add up past `usize::MAX`, described like
+    /// the [`problems`](CategoryMap::problems) of the maps.
+    fn seed_problems(&self) -> Vec<String> {
+        self.seeds
+            .chunks_exact(2)
+            .filter(|pair| pair[0].checked_add(pair[1]).is_none())
+            .map(|pair| {
+                format!(
+                    "seeds, line {}: range {} + {} overflows",
+                    self.seeds_line, pair[0], pair[1]
+                )
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty());
+
+        let Some((seeds_line, seeds)) = lines.next() else {
+            bail!("Expected list of seeds");
+        };
+        let seeds = parse_seeds(seeds)?;
+
+        // Source and destination category, entries and their line numbers of every map.
+        let mut sections: Vec<(&str, &str, Vec<RangeMapEntry>, Vec<usize>)> = Vec::new();
+        for (number, line) in lines {
+            if let Some(header) = line.strip_suffix(" map:") {
+                let (source, destination) = header.split_once("-to-").with_context(|| {
+                    format!("Expected 'source-to-destination map:' on line {number}: {line:?}")
+                })?;
+
+                if sections
+                    .iter()
+                    .any(|section| (section.0, section.1) == (source, destination))
+                {
+                    bail!("Second {source}-to-{destination} map on line {number}");
+                }
+
+                sections.push((source, destination, Vec::new(), Vec::new()));
+                continue;
+            }
+
+            let Some((_, _, entries, lines)) = sections.last_mut() else {
+                bail!("Map entry before the first map header on line {number}: {line:?}");
+            };
+
+            let entry = line
+                .parse::<RangeMapEntry>()
+                .with_context(|| format!("Parsing the mapping entry on line {number}"))?;
+            entries.push(entry);
+            lines.push(number);
+        }
+
+        let maps = sections
+            .into_iter()
+            .map(|(source, destination, entries, lines)| {
+                CategoryMap::with_lines(source, destination, entries, lines)
+            })
+            .collect();
+
+        Ok(Almanac {
+            seeds,
+            maps,
+            seeds_line,
+        })
+    }
+}
+
+/// Parses the numbers on the `seeds: 79 14 55 13` line.
+fn parse_seeds(line: &str) -> Result<Vec<usize>> {
+    let seeds = line
+        .strip_prefix("seeds: ")
+        .with_context(|| format!("Stripping prefix from line «{line}»"))?;
+
+    seeds
+        .split_whitespace()
+        .map(|s| {
+            s.parse::<usize>()
+                .with_context(|| format!("Parsing seed number: {s}"))
+        })
+        .collect::<Result<Vec<usize>>>()
+        .context("Parsing seed numbers")
+}
+
+/// Lowest location number that any of the seeds maps to through all the almanac maps.
+pub fn part1(almanac: &Almanac) -> Result<usize> {
+    let chain = almanac.chain(SEED, LOCATION)?;
+
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| chain.iter().fold(seed, |value, map| map.get(value)))
+        .min()
+        .context("Expected at least one seed")
+}
+
+/// Lowest location number that any seed in the seed ranges maps to.
+///
+/// Instead of mapping every single seed, the ranges are carried through the maps whole,
+/// split into pieces wherever they cross the boundary of a map entry.
+pub fn part2(almanac: &Almanac) -> Result<usize> {
+    let chain = almanac.chain(SEED, LOCATION)?;
+
+    let mut ranges = almanac.seed_ranges()?;
+    for map in chain {
+        ranges = map.map_ranges(&ranges);
+    }
+
+    ranges.min().context("Expected at least one seed")
+}
+
+/// Lowest location number that any seed in the seed ranges maps to, found the other way
+/// round from [`part2`]: by searching upward from the lowest location for the first one
+/// that some seed maps onto.
+///
+/// Whether any location below a bound is reached only gets more likely as the bound grows,
+/// so the bound is doubled until seeds map below it, then narrowed down by bisection,
+/// taking the preimage of the locations below it back through all the maps each time.
+pub fn part2_by_location(almanac: &Almanac) -> Result<usize> {
+    let seeds = almanac.seed_ranges()?;
+    let reached_below = |bound: usize| -> Result<bool> {
+        let below: RangeSet<usize> = std::iter::once(0..bound).collect();
+        let preimage = almanac.preimage(SEED, LOCATION, &below)?;
+        Ok(preimage.intersects(&seeds))
+    };
+
+    let mut high = 1;
+    while !reached_below(high)? {
+        if high == usize::MAX {
+            bail!("Expected at least one seed");
+        }
+        high = high.saturating_mul(2);
+    }
+
+    // Locations below `low` are not reached, but some below `high` are.
+    let mut low = high / 2;
+    while high - low > 1 {
+        let middle = low + (high - low) / 2;
+        if reached_below(middle)? {
+            high = middle;
+        } else {
+            low = middle;
+        }
+    }
+
+    Ok(low)
+}
@@ -0,0 +1,124 @@
+//! Follows single seeds through the maps of the almanac, to explain where they end up.
+
+use std::fmt::Write as _;
+
+use crate::{CategoryMap, RangeMapEntry};
+
+/// Value a seed has in one category, and the entry of the map that gave it.
+#[derive(Debug)]
+pub struct Step<'a> {
+    pub category: &'a str,
+    pub value: usize,
+    /// Entry that mapped the value of the previous category onto this one,
+    /// or `None` when no entry covers it and it maps onto itself.
+    pub entry: Option<&'a RangeMapEntry>,
+}
+
+/// Path of a seed through a chain of maps.
+#[derive(Debug)]
+pub struct Trace<'a> {
+    pub seed: usize,
+    pub steps: Vec<Step<'a>>,
+}
+
+impl<'a> Trace<'a> {
+    /// Follows the `seed` through the `chain` of maps, such as [`Almanac::chain`](crate::Almanac::chain) gives.
+    pub fn new(chain: &[&'a CategoryMap], seed: usize) -> Trace<'a> {
+        let mut value = seed;
+        let steps = chain
+            .iter()
+            .map(|map| {
+                let entry = map.entry(value);
+                value = map.get(value);
+                Step {
+                    category: &map.destination,
+                    value,
+                    entry,
+                }
+            })
+            .collect();
+
+        Trace { seed, steps }
+    }
+}
+
+/// Traces as a table, with a block of one row per category for every seed.
+pub fn table(traces: &[Trace]) -> String {
+    let width = traces
+        .iter()
+        .flat_map(|trace| &trace.steps)
+        .map(|step| step.category.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut table = String::new();
+    for trace in traces {
+        let _ = writeln!(table, "seed {}", trace.seed);
+        for step in &trace.steps {
+            let via = match step.entry {
+                Some(entry) => format!("{entry:?}"),
+                None => "identity".to_string(),
+            };
+            let _ = writeln!(
+                table,
+                "  {:<width$} {:>12}  {via}",
+                step.category, step.value
+            );
+        }
+    }
+
+    table
+}
+
+/// Traces as a JSON array with an object for every seed.
+pub fn json(traces: &[Trace]) -> String {
+    let seeds: Vec<String> = traces
+        .iter()
+        .map(|trace| {
+            let steps: Vec<String> = trace
+                .steps
+                .iter()
+                .map(|step| {
+                    let entry = match step.entry {
+                        Some(entry) => format!(
+                            r#"{{"destination_range_start": {}, "source_range_start": {}, "range_length": {}}}"#,
+                            entry.destination_range_start,
+                            entry.source_range_start,
+                            entry.range_length
+                        ),
+                        None => "null".to_string(),
+                    };
+                    format!(
+                        r#"{{"category": {}, "value": {}, "entry": {entry}}}"#,
+                        json_string(step.category),
+                        step.value
+                    )
+                })
+                .collect();
+
+            format!(
+                "  {{\"seed\": {}, \"steps\": [\n    {}\n  ]}}",
+                trace.seed,
+                steps.join(",\n    ")
+            )
+        })
+        .collect();
+
+    format!("[\n{}\n]\n", seeds.join(",\n"))
+}
+
+fn json_string(s: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", c as u32);
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
@@ -0,0 +1,65 @@
+use day5::{Almanac, LOCATION, SEED};
+
+const EXAMPLE: &str = include_str!("../../../test/day5.txt");
+
+fn sections() -> (&'static str, Vec<&'static str>) {
+    let mut sections = EXAMPLE.trim().split("\n\n");
+    let seeds = sections.next().unwrap();
+    (seeds, sections.collect())
+}
+
+#[test]
+fn reordered_sections_give_the_same_answers() {
+    let (seeds, mut maps) = sections();
+    maps.reverse();
+    let almanac: Almanac = format!("{seeds}\n\n{}", maps.join("\n\n")).parse().unwrap();
+
+    assert_eq!(day5::part1(&almanac).unwrap(), 35);
+    assert_eq!(day5::part2(&almanac).unwrap(), 46);
+}
+
+#[test]
+fn extra_categories_off_the_way_are_ignored() {
+    let input = format!("{}\n\nseed-to-colour map:\n1 2 3\n", EXAMPLE.trim());
+    let almanac: Almanac = input.parse().unwrap();
+
+    assert_eq!(day5::part1(&almanac).unwrap(), 35);
+    assert_eq!(almanac.chain(SEED, LOCATION).unwrap().len(), 7);
+}
+
+#[test]
+fn missing_category_is_reported() {
+    let (seeds, maps) = sections();
+    let maps: Vec<_> = maps
+        .into_iter()
+        .filter(|map| !map.starts_with("water-to-light"))
+        .collect();
+    let almanac: Almanac = format!("{seeds}\n\n{}", maps.join("\n\n")).parse().unwrap();
+
+    let error = day5::part1(&almanac).unwrap_err().to_string();
+    assert_eq!(
+        error,
+        "No way from the seed category to the location category; \
+         the maps only lead from seed to soil, fertilizer, water"
+    );
+}
+
+#[test]
+fn two_ways_between_categories_are_ambiguous() {
+    let input = format!("{}\n\nseed-to-location map:\n1 2 3\n", EXAMPLE.trim());
+    let almanac: Almanac = input.parse().unwrap();
+
+    let error = almanac.chain(SEED, LOCATION).unwrap_err().to_string();
+    assert!(error.starts_with("Ambiguous way"), "{error}");
+}
+
+#[test]
+fn duplicate_map_is_rejected() {
+    let input = format!("{}\n\nseed-to-soil map:\n1 2 3\n", EXAMPLE.trim());
+    let error = input.parse::<Almanac>().unwrap_err().to_string();
+
+    assert!(
+        error.starts_with("Second seed-to-soil map on line"),
+        "{error}"
+    );
+}
@@ -0,0 +1,39 @@
+use day5::{Almanac, CategoryMap, RangeMapEntry, LOCATION, SEED};
+
+const EXAMPLE: &str = include_str!("../../../test/day5.txt");
+
+#[test]
+fn composed_map_agrees_with_walking_the_chain() {
+    let almanac: Almanac = EXAMPLE.parse().unwrap();
+    let chain = almanac.chain(SEED, LOCATION).unwrap();
+    let composed = almanac.compose(SEED, LOCATION).unwrap();
+
+    for seed in 0..200 {
+        let walked = chain.iter().fold(seed, |value, map| map.get(value));
+        assert_eq!(composed.get(seed), walked, "seed {seed}");
+    }
+}
+
+#[test]
+fn composed_map_answers_range_queries() {
+    let almanac: Almanac = EXAMPLE.parse().unwrap();
+    let composed = almanac.compose(SEED, LOCATION).unwrap();
+
+    let lowest = composed.map_ranges(&almanac.seed_ranges().unwrap()).min();
+    assert_eq!(lowest, Some(day5::part2(&almanac).unwrap()));
+}
+
+#[test]
+fn composition_splits_at_the_entries_of_both_maps() {
+    let first = CategoryMap::new("a", "b", vec![RangeMapEntry::new(100, 0, 10)]);
+    let second = CategoryMap::new(
+        "b",
+        "c",
+        vec![RangeMapEntry::new(0, 105, 10), RangeMapEntry::new(8, 20, 2)],
+    );
+
+    assert_eq!(
+        format!("{:?}", first.compose(&second)),
+        "a-to-c [0..5 → 100..105 (∆100), 5..10 → 0..5 (∆-5), 20..22 → 8..10 (∆-12), 105..115 → 0..10 (∆-105)]"
+    );
+}
@@ -0,0 +1,116 @@
+use aoc_common::test_support::Rng;
+use day5::{Almanac, CategoryMap, RangeMapEntry, LOCATION, SEED};
+use intervals::RangeSet;
+
+const EXAMPLE: &str = include_str!("../../../test/day5.txt");
+
+/// Almanac like the puzzle input, with `entries` adjacent source ranges in every map,
+/// shuffled onto destination ranges that may overlap.
+fn generate_almanac(rng: &mut Rng, entries: usize) -> String {
+    let categories = [
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+
+    let seeds: Vec<String> = (0..4)
+        .map(|_| format!("{} {}", rng.below(10_000), 1 + rng.below(500)))
+        .collect();
+    let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+
+    for pair in categories.windows(2) {
+        almanac.push_str(&format!("\n{}-to-{} map:\n", pair[0], pair[1]));
+        let mut source = rng.below(100);
+        for _ in 0..entries {
+            let length = 1 + rng.below(1_000);
+            almanac.push_str(&format!("{} {source} {length}\n", rng.below(10_000)));
+            source += length + rng.below(100);
+        }
+    }
+
+    almanac
+}
+
+#[test]
+fn searching_upward_from_the_lowest_location_agrees_with_part2() {
+    let almanac: Almanac = EXAMPLE.parse().unwrap();
+    assert_eq!(day5::part2_by_location(&almanac).unwrap(), 46);
+
+    let mut rng = Rng(0x2023_1205);
+    for _ in 0..50 {
+        let almanac: Almanac = generate_almanac(&mut rng, 12).parse().unwrap();
+        assert_eq!(
+            day5::part2_by_location(&almanac).unwrap(),
+            day5::part2(&almanac).unwrap()
+        );
+    }
+}
+
+#[test]
+fn preimage_holds_every_seed_reaching_the_locations() {
+    let almanac: Almanac = EXAMPLE.parse().unwrap();
+    let chain = almanac.chain(SEED, LOCATION).unwrap();
+    let locations = RangeSet::from_iter([40..44, 44..50]);
+    let preimage = almanac.preimage(SEED, LOCATION, &locations).unwrap();
+
+    for seed in 0..200 {
+        let location = chain.iter().fold(seed, |value, map| map.get(value));
+        assert_eq!(
+            preimage.contains(&seed),
+            locations.contains(&location),
+            "seed {seed}"
+        );
+    }
+}
+
+#[test]
+fn inverting_an_entry_undoes_it() {
+    let entry = RangeMapEntry::new(50, 98, 2);
+
+    assert_eq!(entry.invert(51), Some(99));
+    assert_eq!(entry.invert(52), None);
+    assert_eq!(entry.invert(51).and_then(|seed| entry.get(seed)), Some(51));
+}
+
+#[test]
+fn inverting_a_map_finds_every_source() {
+    let entries = vec![RangeMapEntry::new(10, 0, 5), RangeMapEntry::new(12, 20, 5)];
+    let map = CategoryMap::new("a", "b", entries);
+
+    assert_eq!(map.invert(13), vec![3, 13, 21]);
+    assert_eq!(map.invert(2), Vec::<usize>::new());
+    assert_eq!(map.invert(17), vec![17]);
+    assert_eq!(
+        map.preimage(&RangeSet::from_iter([12..13, 13..14]))
+            .ranges(),
+        [2..4, 12..14, 20..22]
+    );
+}
+
+#[test]
+fn inverting_overflowing_entries_stays_within_their_ranges() {
+    let entry = RangeMapEntry::new(0, usize::MAX - 5, 10);
+
+    assert_eq!(entry.invert(3), Some(usize::MAX - 2));
+    assert_eq!(entry.invert(7), None);
+
+    let entries = vec![entry, RangeMapEntry::new(usize::MAX - 5, 0, 10)];
+    let map = CategoryMap::new("a", "b", entries);
+
+    assert_eq!(map.invert(7), Vec::<usize>::new());
+
+    let range = |range| std::iter::once(range).collect::<RangeSet<usize>>();
+    assert_eq!(
+        map.preimage(&range(0..10)),
+        range(usize::MAX - 5..usize::MAX)
+    );
+    assert_eq!(
+        map.preimage(&range(usize::MAX - 3..usize::MAX)),
+        range(2..5)
+    );
+}
@@ -0,0 +1,125 @@
+use std::process::Output;
+
+use aoc_common::test_support;
+
+const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../test/day5.txt");
+const EXPECTED: &str = "Day 5, part 1: 35\nDay 5, part 2: 46\n";
+
+fn run(args: &[&str], stdin: Option<&[u8]>) -> Output {
+    test_support::run(env!("CARGO_BIN_EXE_day5"), args, stdin)
+}
+
+#[test]
+fn reads_piped_stdin_for_dash() {
+    let input = std::fs::read(FIXTURE).unwrap();
+    let output = run(&["-"], Some(&input));
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), EXPECTED);
+}
+
+#[test]
+fn reads_file_named_by_argument() {
+    let output = run(&[FIXTURE], None);
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), EXPECTED);
+}
+
+#[test]
+fn traces_every_seed_to_its_location() {
+    let output = run(&[FIXTURE, "--trace"], None);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.starts_with("seed 79\n  soil "), "{stdout}");
+    assert!(
+        stdout.contains("location              82  56..93 → 60..97 (∆4)"),
+        "{stdout}"
+    );
+
+    let output = run(&[FIXTURE, "--trace", "json"], None);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(
+        stdout.contains(r#"{"category": "fertilizer", "value": 81, "entry": null}"#),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn strict_mode_rejects_overlapping_ranges() {
+    let output = run(&[FIXTURE, "--strict"], None);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), EXPECTED);
+
+    let input = b"seeds: 1 2\n\nseed-to-location map:\n0 10 20\n100 25 10\n";
+    let output = run(&["-", "--strict"], Some(input));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("lines 4 and 5: source ranges 10..30 and 25..35 overlap"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn draws_the_maps_as_dot() {
+    let output = run(&[FIXTURE, "--dot"], None);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.starts_with("digraph almanac {"), "{stdout}");
+    assert!(
+        stdout.contains(
+            r#""seed" -> "soil" [label="2 entries\n50 numbers covered\n100.0% of 50..100"];"#
+        ),
+        "{stdout}"
+    );
+
+    let output = run(&[FIXTURE, "--dot", "--split", "79..93"], None);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(
+        stdout.contains(r#""4:0" -> "5:1" [label="77..88 (∆-32)"];"#),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn reversed_split_range_is_rejected() {
+    let output = run(&[FIXTURE, "--dot", "--split", "93..79"], None);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("Expected a range of seeds that does not end before it starts"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn only_one_kind_of_output_is_printed() {
+    for args in [
+        ["--dot", "--trace"],
+        ["--dot", "--composed"],
+        ["--composed", "--trace"],
+    ] {
+        let output = run(&[&[FIXTURE][..], &args].concat(), None);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(!output.status.success(), "{args:?}");
+        assert!(output.stdout.is_empty(), "{args:?}");
+        assert!(stderr.contains("cannot be used with"), "{stderr}");
+    }
+}
@@ -0,0 +1,184 @@
+use day5::{Almanac, CategoryMap, RangeMapEntry};
+
+const EXAMPLE: &str = include_str!("../../../test/day5.txt");
+
+#[test]
+fn example_is_valid() {
+    let almanac: Almanac = EXAMPLE.parse().unwrap();
+    almanac.validate().unwrap();
+}
+
+#[test]
+fn gaps_between_entries_are_valid() {
+    let map = CategoryMap::new(
+        "a",
+        "b",
+        vec![
+            RangeMapEntry::new(100, 0, 10),
+            RangeMapEntry::new(0, 50, 10),
+        ],
+    );
+    assert!(map.problems().is_empty());
+}
+
+#[test]
+fn overlapping_source_ranges_are_reported_with_line_numbers() {
+    let almanac: Almanac = "seeds: 1\n\na-to-b map:\n0 10 20\n100 40 5\n50 25 10\n"
+        .parse()
+        .unwrap();
+
+    let error = almanac.validate().unwrap_err().to_string();
+    assert_eq!(
+        error,
+        "1 problem(s) in the almanac:\n  a-to-b map, lines 4 and 6: source ranges 10..30 and 25..35 overlap"
+    );
+}
+
+#[test]
+fn first_entry_in_the_input_applies_where_source_ranges_overlap() {
+    let almanac: Almanac = "seeds: 1\n\na-to-b map:\n100 20 10\n0 10 20\n"
+        .parse()
+        .unwrap();
+    let map = &almanac.maps[0];
+
+    assert_eq!(map.get(25), 105);
+    assert_eq!(map.get(15), 5);
+    assert_eq!(map.split(10..30), vec![(10..20, 0..10), (20..30, 100..110)]);
+
+    let map = CategoryMap::new(
+        "a",
+        "b",
+        vec![
+            RangeMapEntry::new(0, 10, 20),
+            RangeMapEntry::new(100, 20, 10),
+        ],
+    );
+    assert_eq!(map.get(25), 15);
+}
+
+#[test]
+fn overlapping_destination_ranges_are_reported() {
+    let map = CategoryMap::new(
+        "a",
+        "b",
+        vec![
+            RangeMapEntry::new(0, 0, 10),
+            RangeMapEntry::new(5, 20, 10),
+            RangeMapEntry::new(30, 40, 1),
+        ],
+    );
+
+    assert_eq!(
+        map.problems(),
+        ["a-to-b map, entries 1 and 2: destination ranges 0..10 and 5..15 overlap"]
+    );
+}
+
+#[test]
+fn entries_are_reported_in_the_order_they_were_given_in() {
+    let map = CategoryMap::new(
+        "a",
+        "b",
+        vec![
+            RangeMapEntry::new(100, usize::MAX - 1, 5),
+            RangeMapEntry::new(30, 40, 1),
+            RangeMapEntry::new(5, 20, 10),
+            RangeMapEntry::new(0, 0, 10),
+        ],
+    );
+
+    assert_eq!(
+        map.problems(),
+        [
+            format!(
+                "a-to-b map, entry 1: source range {} + 5 overflows",
+                usize::MAX - 1
+            ),
+            "a-to-b map, entries 4 and 3: destination ranges 0..10 and 5..15 overlap".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn overflowing_ranges_are_reported() {
+    let map = CategoryMap::new(
+        "a",
+        "b",
+        vec![
+            RangeMapEntry::new(usize::MAX, 0, 2),
+            RangeMapEntry::new(0, usize::MAX - 1, 1),
+        ],
+    );
+
+    assert_eq!(
+        map.problems(),
+        [format!(
+            "a-to-b map, entry 1: destination range {} + 2 overflows",
+            usize::MAX
+        )]
+    );
+}
+
+#[test]
+fn overflowing_entries_are_cut_short_without_strict() {
+    let entry = RangeMapEntry::new(5, usize::MAX - 5, 10);
+
+    assert_eq!(entry.get(usize::MAX - 1), Some(9));
+    assert_eq!(entry.get(3), None);
+    assert_eq!(
+        format!("{entry:?}"),
+        format!(
+            "{}..{} → 5..15 (∆-{})",
+            usize::MAX - 5,
+            usize::MAX,
+            usize::MAX - 10
+        )
+    );
+}
+
+#[test]
+fn overflowing_destinations_are_cut_short_when_mapping_ranges() {
+    let almanac: Almanac = format!(
+        "seeds: 0 10\n\nseed-to-location map:\n{} 0 10\n",
+        usize::MAX - 5
+    )
+    .parse()
+    .unwrap();
+
+    assert_eq!(day5::part2(&almanac).unwrap(), usize::MAX - 5);
+    assert_eq!(
+        almanac.maps[0].split(0..20),
+        vec![(0..10, usize::MAX - 5..usize::MAX), (10..20, 10..20)]
+    );
+    assert_eq!(
+        format!("{:?}", almanac.compose("seed", "location").unwrap()),
+        format!(
+            "seed-to-location [0..10 → {}..{} (∆{})]",
+            usize::MAX - 5,
+            usize::MAX,
+            usize::MAX - 5
+        )
+    );
+}
+
+#[test]
+fn overflowing_seed_ranges_are_reported() {
+    let almanac: Almanac = format!(
+        "\nseeds: 6 {}\n\nseed-to-location map:\n0 0 1\n",
+        usize::MAX - 1
+    )
+    .parse()
+    .unwrap();
+
+    assert_eq!(
+        almanac.seed_ranges().unwrap_err().to_string(),
+        format!("Seed range 6 + {} on line 2 overflows", usize::MAX - 1)
+    );
+    assert_eq!(
+        almanac.validate().unwrap_err().to_string(),
+        format!(
+            "1 problem(s) in the almanac:\n  seeds, line 2: range 6 + {} overflows",
+            usize::MAX - 1
+        )
+    );
+}
@@ -0,0 +1,133 @@
+use std::fmt::{Debug, Formatter};
+use std::ops::Range;
+
+/// Map from ranges of keys onto values, with the ranges sorted and not overlapping.
+///
+/// Keys outside of all the ranges have no value.
+#[derive(Clone, PartialEq, Eq)]
+pub struct IntervalMap<K, V> {
+    entries: Vec<(Range<K>, V)>,
+}
+
+impl<K, V> Default for IntervalMap<K, V> {
+    fn default() -> Self {
+        IntervalMap {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<K: Ord + Copy, V: Clone> IntervalMap<K, V> {
+    pub fn new() -> Self {
+        IntervalMap::default()
+    }
+
+    /// Number of ranges in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Ranges of the map and their values, sorted by the ranges.
+    pub fn iter(&self) -> impl Iterator<Item = (&Range<K>, &V)> {
+        self.entries.iter().map(|(range, value)| (range, value))
+    }
+
+    /// Value of the range the `key` falls into.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    /// Range the `key` falls into, with its value.
+    pub fn get_key_value(&self, key: &K) -> Option<(&Range<K>, &V)> {
+        let index = self.entries.partition_point(|(range, _)| range.end <= *key);
+        self.entries
+            .get(index)
+            .filter(|(range, _)| range.start <= *key)
+            .map(|(range, value)| (range, value))
+    }
+
+    /// Maps the keys of the `range` onto the `value`, replacing whatever values the parts
+    /// of the ranges it overlaps had. The parts outside of it keep their values.
+    pub fn insert(&mut self, range: Range<K>, value: V) {
+        if range.is_empty() {
+            return;
+        }
+
+        let first = self
+            .entries
+            .partition_point(|(other, _)| other.end <= range.start);
+        let last = self
+            .entries
+            .partition_point(|(other, _)| other.start < range.end);
+
+        let mut replacement = Vec::with_capacity(3);
+        if let Some((other, other_value)) = self.entries.get(first).filter(|_| first < last) {
+            if other.start < range.start {
+                replacement.push((other.start..range.start, other_value.clone()));
+            }
+        }
+        let end = range.end;
+        replacement.push((range, value));
+        if let Some((other, other_value)) = last.checked_sub(1).and_then(|i| self.entries.get(i)) {
+            if first < last && end < other.end {
+                replacement.push((end..other.end, other_value.clone()));
+            }
+        }
+
+        self.entries.splice(first..last, replacement);
+    }
+
+    /// Splits the `range` of keys at the boundaries of the ranges in the map, pairing every
+    /// part with its value, or with `None` in the gaps between them.
+    pub fn split(&self, range: Range<K>) -> Vec<(Range<K>, Option<&V>)> {
+        let mut parts = Vec::new();
+        let mut start = range.start;
+        let first = self
+            .entries
+            .partition_point(|(other, _)| other.end <= start);
+
+        for (other, value) in &self.entries[first..] {
+            if start >= range.end || other.start >= range.end {
+                break;
+            }
+
+            if start < other.start {
+                parts.push((start..other.start, None));
+                start = other.start;
+            }
+
+            let end = range.end.min(other.end);
+            parts.push((start..end, Some(value)));
+            start = end;
+        }
+
+        if start < range.end {
+            parts.push((start..range.end, None));
+        }
+
+        parts
+    }
+}
+
+impl<K: Ord + Copy, V: Clone> FromIterator<(Range<K>, V)> for IntervalMap<K, V> {
+    /// Collects the ranges in order, later ones overriding the ones before where they overlap.
+    fn from_iter<I: IntoIterator<Item = (Range<K>, V)>>(iter: I) -> Self {
+        let mut map = IntervalMap::new();
+        for (range, value) in iter {
+            map.insert(range, value);
+        }
+        map
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for IntervalMap<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.entries.iter().map(|(range, value)| (range, value)))
+            .finish()
+    }
+}
@@ -0,0 +1,12 @@
+//! Sets and maps of half-open ranges, for puzzles whose numbers are too many to handle
+//! one by one but come in long runs that are all treated alike.
+//!
+//! Both types keep their ranges sorted and apart from each other, so that finding the
+//! range a single value falls into is a binary search, and that combining two of them
+//! is a single pass over both.
+
+pub use interval_map::IntervalMap;
+pub use range_set::RangeSet;
+
+mod interval_map;
+mod range_set;
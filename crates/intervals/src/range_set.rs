@@ -0,0 +1,208 @@
+use std::fmt::{Debug, Formatter};
+use std::ops::Range;
+
+/// Set of values stored as sorted ranges that neither overlap nor touch.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Ord + Copy> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet::default()
+    }
+
+    /// Ranges of the set, sorted, with gaps between them.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Range<T>> {
+        self.ranges.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= *value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start <= *value)
+    }
+
+    /// Adds the values of the `range`, joining it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|other| other.end < range.start);
+        let last = self
+            .ranges
+            .partition_point(|other| other.start <= range.end);
+
+        let mut joined = range;
+        if first < last {
+            joined.start = joined.start.min(self.ranges[first].start);
+            joined.end = joined.end.max(self.ranges[last - 1].end);
+        }
+
+        self.ranges.splice(first..last, [joined]);
+    }
+
+    /// Values in either set.
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut ranges: Vec<Range<T>> = Vec::with_capacity(self.ranges.len() + other.ranges.len());
+        let (mut left, mut right) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+
+        loop {
+            let next = match (left.peek(), right.peek()) {
+                (Some(a), Some(b)) if a.start <= b.start => left.next(),
+                (Some(_), Some(_)) => right.next(),
+                (Some(_), None) => left.next(),
+                (None, Some(_)) => right.next(),
+                (None, None) => break,
+            };
+            let next = next.unwrap().clone();
+
+            match ranges.last_mut() {
+                Some(last) if next.start <= last.end => last.end = last.end.max(next.end),
+                _ => ranges.push(next),
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    /// Values in both sets.
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            if a.end <= b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    /// Values in this set but not in the `other`.
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+
+        for range in &self.ranges {
+            let mut start = range.start;
+            while let Some(b) = other.ranges.get(j) {
+                if b.end <= start {
+                    j += 1;
+                    continue;
+                }
+                if b.start >= range.end {
+                    break;
+                }
+
+                if start < b.start {
+                    ranges.push(start..b.start);
+                }
+                start = b.end;
+                if b.end > range.end {
+                    break;
+                }
+                j += 1;
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    /// Whether the sets have any value in common.
+    pub fn intersects(&self, other: &RangeSet<T>) -> bool {
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            if a.start.max(b.start) < a.end.min(b.end) {
+                return true;
+            }
+
+            if a.end <= b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        false
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut ranges: Vec<Range<T>> =
+            iter.into_iter().filter(|range| !range.is_empty()).collect();
+        ranges.sort_by_key(|range| range.start);
+
+        let mut joined: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match joined.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => joined.push(range),
+            }
+        }
+
+        RangeSet { ranges: joined }
+    }
+}
+
+impl<T: Ord + Copy> Extend<Range<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RangeSet<T> {
+    type Item = &'a Range<T>;
+    type IntoIter = std::slice::Iter<'a, Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.iter()
+    }
+}
+
+impl<T: Debug> Debug for RangeSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(&self.ranges).finish()
+    }
+}
@@ -0,0 +1,132 @@
+//! Checks the range types against naive models that keep every single value.
+
+use std::ops::Range;
+
+use intervals::{IntervalMap, RangeSet};
+use proptest::prelude::*;
+
+/// Number of values the models keep, and the upper bound of the generated ranges.
+const DOMAIN: u8 = 64;
+
+fn range() -> impl Strategy<Value = Range<u8>> {
+    (0..DOMAIN, 0..DOMAIN).prop_map(|(start, end)| start..end)
+}
+
+fn ranges() -> impl Strategy<Value = Vec<Range<u8>>> {
+    prop::collection::vec(range(), 0..8)
+}
+
+/// Ranges to insert into an [`IntervalMap`], with one of a few values each.
+fn inserts() -> impl Strategy<Value = Vec<(Range<u8>, u8)>> {
+    prop::collection::vec((range(), 0..4u8), 0..8)
+}
+
+/// Set of values as a flag for every value in the domain.
+fn model(ranges: &[Range<u8>]) -> Vec<bool> {
+    (0..DOMAIN)
+        .map(|value| ranges.iter().any(|range| range.contains(&value)))
+        .collect()
+}
+
+fn assert_normalized(set: &RangeSet<u8>) {
+    for range in set.ranges() {
+        assert!(range.start < range.end, "empty range in {set:?}");
+    }
+    for pair in set.ranges().windows(2) {
+        assert!(
+            pair[0].end < pair[1].start,
+            "ranges overlap or touch in {set:?}"
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn collecting_keeps_the_values_of_all_the_ranges(ranges in ranges()) {
+        let set: RangeSet<u8> = ranges.iter().cloned().collect();
+
+        assert_normalized(&set);
+        prop_assert_eq!(model(set.ranges()), model(&ranges));
+    }
+
+    #[test]
+    fn inserting_one_by_one_equals_collecting(ranges in ranges()) {
+        let mut set = RangeSet::new();
+        set.extend(ranges.iter().cloned());
+
+        assert_normalized(&set);
+        prop_assert_eq!(set, ranges.into_iter().collect::<RangeSet<u8>>());
+    }
+
+    #[test]
+    fn contains_agrees_with_the_model(ranges in ranges(), value in 0..DOMAIN) {
+        let set: RangeSet<u8> = ranges.iter().cloned().collect();
+        prop_assert_eq!(set.contains(&value), model(&ranges)[value as usize]);
+    }
+
+    #[test]
+    fn set_operations_agree_with_the_model(a in ranges(), b in ranges()) {
+        let (set_a, set_b): (RangeSet<u8>, RangeSet<u8>) =
+            (a.iter().cloned().collect(), b.iter().cloned().collect());
+        let (model_a, model_b) = (model(&a), model(&b));
+
+        let union = set_a.union(&set_b);
+        let intersection = set_a.intersection(&set_b);
+        let difference = set_a.difference(&set_b);
+        for set in [&union, &intersection, &difference] {
+            assert_normalized(set);
+        }
+
+        let expected = |op: fn(bool, bool) -> bool| -> Vec<bool> {
+            model_a.iter().zip(&model_b).map(|(&x, &y)| op(x, y)).collect()
+        };
+        prop_assert_eq!(model(union.ranges()), expected(|x, y| x || y));
+        prop_assert_eq!(model(intersection.ranges()), expected(|x, y| x && y));
+        prop_assert_eq!(model(difference.ranges()), expected(|x, y| x && !y));
+        prop_assert_eq!(set_a.intersects(&set_b), !intersection.is_empty());
+    }
+
+    #[test]
+    fn interval_map_agrees_with_the_model(inserts in inserts()) {
+        let map: IntervalMap<u8, u8> = inserts.iter().cloned().collect();
+
+        let mut model = vec![None; DOMAIN as usize];
+        for (range, value) in &inserts {
+            for key in range.clone() {
+                model[key as usize] = Some(*value);
+            }
+        }
+
+        let ranges: Vec<&Range<u8>> = map.iter().map(|(range, _)| range).collect();
+        for pair in ranges.windows(2) {
+            prop_assert!(pair[0].end <= pair[1].start, "ranges overlap in {:?}", map);
+        }
+        for key in 0..DOMAIN {
+            prop_assert_eq!(map.get(&key).copied(), model[key as usize]);
+        }
+    }
+
+    #[test]
+    fn splitting_covers_the_range_with_the_values(
+        inserts in inserts(),
+        range in range(),
+    ) {
+        let map: IntervalMap<u8, u8> = inserts.into_iter().collect();
+        let parts = map.split(range.clone());
+
+        let mut next = range.start;
+        for (part, value) in &parts {
+            prop_assert_eq!(part.start, next);
+            prop_assert!(part.start < part.end);
+            for key in part.clone() {
+                prop_assert_eq!(map.get(&key), *value);
+            }
+            next = part.end;
+        }
+        if range.is_empty() {
+            prop_assert!(parts.is_empty());
+        } else {
+            prop_assert_eq!(next, range.end);
+        }
+    }
+}
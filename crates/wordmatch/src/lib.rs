@@ -0,0 +1,186 @@
+//! Finds all the words of a vocabulary in a text in a single pass, the way the
+//! Aho-Corasick algorithm does: the words are built into an automaton once, which then
+//! reads the text byte by byte, never looking back.
+//!
+//! Matches may overlap, so that `twone` holds both `two` and `one`.
+
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter};
+
+/// State the automaton starts in, before it has read anything.
+const ROOT: usize = 0;
+
+/// Automaton finding all the words it was built from in a text, each of them standing for
+/// a value of type `T`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Automaton<T> {
+    /// Words and their values, in the order they were given.
+    words: Vec<(Vec<u8>, T)>,
+    /// State after reading each byte in each state, 256 entries per state.
+    transitions: Vec<usize>,
+    /// Words that end in each state, the longest first.
+    outputs: Vec<Vec<usize>>,
+}
+
+/// Word found in a text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'a, T> {
+    /// Index of the word among the words the automaton was built from.
+    pub word: usize,
+    /// Byte offset of the word in the text.
+    pub start: usize,
+    /// Byte offset just after the end of the word.
+    pub end: usize,
+    pub value: &'a T,
+}
+
+impl<T> Automaton<T> {
+    /// Builds the automaton from the `words`, each paired with the value it stands for.
+    ///
+    /// Empty words are never found.
+    pub fn new<W: AsRef<[u8]>>(words: impl IntoIterator<Item = (W, T)>) -> Automaton<T> {
+        let words: Vec<(Vec<u8>, T)> = words
+            .into_iter()
+            .map(|(word, value)| (word.as_ref().to_vec(), value))
+            .collect();
+
+        // Trie of the words, with `None` where a state has no child for a byte yet.
+        let mut children: Vec<[Option<usize>; 256]> = vec![[None; 256]];
+        let mut outputs: Vec<Vec<usize>> = vec![Vec::new()];
+        for (index, (word, _)) in words.iter().enumerate() {
+            if word.is_empty() {
+                continue;
+            }
+
+            let mut state = ROOT;
+            for &byte in word {
+                state = match children[state][byte as usize] {
+                    Some(child) => child,
+                    None => {
+                        children.push([None; 256]);
+                        outputs.push(Vec::new());
+                        children[state][byte as usize] = Some(children.len() - 1);
+                        children.len() - 1
+                    }
+                };
+            }
+            outputs[state].push(index);
+        }
+
+        // Breadth first, so that the failure state of every state, the state of the longest
+        // proper suffix of its path that is also in the trie, is complete before it is needed.
+        let mut transitions = vec![ROOT; children.len() * 256];
+        let mut failures = vec![ROOT; children.len()];
+        let mut queue = VecDeque::new();
+
+        for byte in 0..256 {
+            if let Some(child) = children[ROOT][byte] {
+                transitions[byte] = child;
+                queue.push_back(child);
+            }
+        }
+
+        while let Some(state) = queue.pop_front() {
+            let inherited = outputs[failures[state]].clone();
+            outputs[state].extend(inherited);
+
+            for byte in 0..256 {
+                let fallback = transitions[failures[state] * 256 + byte];
+                match children[state][byte] {
+                    Some(child) => {
+                        failures[child] = fallback;
+                        transitions[state * 256 + byte] = child;
+                        queue.push_back(child);
+                    }
+                    None => transitions[state * 256 + byte] = fallback,
+                }
+            }
+        }
+
+        Automaton {
+            words,
+            transitions,
+            outputs,
+        }
+    }
+
+    /// Words the automaton was built from, with their values.
+    pub fn words(&self) -> impl Iterator<Item = (&[u8], &T)> {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_slice(), value))
+    }
+
+    /// All the words in the `text`, overlapping ones included, in the order they end.
+    /// Words ending at the same byte come longest first.
+    pub fn find_overlapping<'a>(
+        &'a self,
+        text: &'a (impl AsRef<[u8]> + ?Sized),
+    ) -> FindOverlapping<'a, T> {
+        FindOverlapping {
+            automaton: self,
+            text: text.as_ref(),
+            at: 0,
+            state: ROOT,
+            pending: &[],
+        }
+    }
+
+    /// Number of states of the automaton.
+    pub fn states(&self) -> usize {
+        self.outputs.len()
+    }
+}
+
+/// Iterator over the words an [`Automaton`] finds in a text.
+pub struct FindOverlapping<'a, T> {
+    automaton: &'a Automaton<T>,
+    text: &'a [u8],
+    /// Offset of the next byte to read.
+    at: usize,
+    state: usize,
+    /// Words ending just before `at` that are still to be reported.
+    pending: &'a [usize],
+}
+
+impl<'a, T> Iterator for FindOverlapping<'a, T> {
+    type Item = Match<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let automaton = self.automaton;
+
+        while self.pending.is_empty() {
+            let &byte = self.text.get(self.at)?;
+            self.state = automaton.transitions[self.state * 256 + byte as usize];
+            self.pending = &automaton.outputs[self.state];
+            self.at += 1;
+        }
+
+        let (&word, rest) = self.pending.split_first()?;
+        self.pending = rest;
+
+        let (found, value) = &automaton.words[word];
+        Some(Match {
+            word,
+            start: self.at - found.len(),
+            end: self.at,
+            value,
+        })
+    }
+}
+
+impl<T: Debug> Debug for Automaton<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Automaton")
+            .field(
+                "words",
+                &self
+                    .words
+                    .iter()
+                    .map(|(word, value)| (String::from_utf8_lossy(word), value))
+                    .collect::<Vec<_>>(),
+            )
+            .field("states", &self.states())
+            .finish()
+    }
+}
@@ -0,0 +1,62 @@
+use proptest::prelude::*;
+use wordmatch::Automaton;
+
+fn found(automaton: &Automaton<u32>, text: &str) -> Vec<(usize, usize, u32)> {
+    automaton
+        .find_overlapping(text)
+        .map(|found| (found.start, found.end, *found.value))
+        .collect()
+}
+
+#[test]
+fn overlapping_words_are_all_found() {
+    let automaton = Automaton::new([("one", 1), ("two", 2), ("eight", 8)]);
+
+    assert_eq!(found(&automaton, "twone"), [(0, 3, 2), (2, 5, 1)]);
+    assert_eq!(
+        found(&automaton, "eightwone"),
+        [(0, 5, 8), (4, 7, 2), (6, 9, 1)]
+    );
+    assert_eq!(found(&automaton, "xyz"), []);
+}
+
+#[test]
+fn words_inside_other_words_are_found() {
+    let automaton = Automaton::new([("he", 0), ("she", 1), ("his", 2), ("hers", 3)]);
+
+    assert_eq!(
+        found(&automaton, "ushers"),
+        [(1, 4, 1), (2, 4, 0), (2, 6, 3)]
+    );
+}
+
+#[test]
+fn words_may_be_any_utf8() {
+    let automaton = Automaton::new([("üks", 1), ("kaks", 2), ("üheksa", 9)]);
+
+    assert_eq!(found(&automaton, "üheksakaks"), [(0, 7, 9), (7, 11, 2)]);
+}
+
+proptest! {
+    #[test]
+    fn finds_the_same_as_searching_at_every_offset(
+        words in prop::collection::vec("[ab]{1,4}", 1..6),
+        text in "[abc]{0,30}",
+    ) {
+        let automaton = Automaton::new(words.iter().zip(0..));
+
+        let mut expected = Vec::new();
+        for end in 1..=text.len() {
+            for (index, word) in words.iter().enumerate() {
+                if text[..end].ends_with(word.as_str()) {
+                    expected.push((end - word.len(), end, index as u32));
+                }
+            }
+        }
+
+        let mut actual = found(&automaton, &text);
+        actual.sort_by_key(|&(start, end, value)| (end, value, start));
+        expected.sort_by_key(|&(start, end, value)| (end, value, start));
+        prop_assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,210 @@
+//! Times the parsing and both parts of the puzzles separately.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use aoc_common::{default_filename, Input, Puzzle};
+
+/// Phases of solving a puzzle that are timed separately.
+pub const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Summary of the times measured for a phase.
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// Statistics of the `samples`, of which there must be at least one.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+
+        let min = samples[0];
+        let median = samples[samples.len() / 2];
+        let mean = samples.iter().sum::<Duration>() / samples.len() as u32;
+
+        Stats { min, median, mean }
+    }
+}
+
+/// Runs the parsing and both parts of the `puzzle` on the `text` of its input
+/// `iterations` times, at least once, returning the statistics of each of the [`PHASES`].
+///
+/// A part that fails is not run again, and its error is returned in place of its
+/// statistics. Without a parsed input there is nothing to time the parts on, so a failure
+/// to parse fails the whole benchmark.
+pub fn bench(puzzle: &dyn Puzzle, text: &str, iterations: usize) -> Result<[Result<Stats>; 3]> {
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+    let mut failures: [Option<anyhow::Error>; 3] = Default::default();
+
+    for _ in 0..iterations {
+        let input = Input::from_string("<bench>", text.to_string());
+
+        let start = Instant::now();
+        let parsed = puzzle.parse(input)?;
+        samples[0].push(start.elapsed());
+
+        let parts: [&dyn Fn() -> Result<String>; 2] = [&|| parsed.part1(), &|| parsed.part2()];
+        for (phase, part) in (1..).zip(parts) {
+            if failures[phase].is_some() {
+                continue;
+            }
+
+            let start = Instant::now();
+            match part() {
+                Ok(_) => samples[phase].push(start.elapsed()),
+                Err(error) => failures[phase] = Some(error),
+            }
+        }
+    }
+
+    let mut failures = failures.into_iter();
+    Ok(samples.map(|samples| match failures.next().flatten() {
+        Some(error) => Err(error),
+        None => Ok(Stats::from_samples(samples)),
+    }))
+}
+
+/// Benchmarks the `puzzles` on their `dayN.txt` inputs in the `inputs` directory and prints
+/// the statistics of every phase, compared to the `baseline` when one is given. Phases that
+/// fail are reported in place of their statistics, and the other puzzles are still run.
+///
+/// Returns the median times of this run, to be saved as a new baseline.
+pub fn run(
+    puzzles: &[&dyn Puzzle],
+    inputs: &Path,
+    iterations: usize,
+    baseline: Option<&Baseline>,
+) -> Result<Baseline> {
+    let mut medians = Baseline::default();
+
+    for puzzle in puzzles {
+        let day = puzzle.day();
+        let path = inputs.join(default_filename(day));
+        if !path.exists() && puzzles.len() > 1 {
+            println!("Day {day}: no input at {}, skipped", path.display());
+            continue;
+        }
+
+        let text = fs::read_to_string(&path).with_context(|| format!("Reading {path:?}"))?;
+        let stats = match bench(*puzzle, &text, iterations) {
+            Ok(stats) => stats,
+            Err(error) => {
+                println!("Day {day}: failed to parse the input: {error:#}");
+                continue;
+            }
+        };
+
+        println!("Day {day}: {} ({iterations} iterations)", puzzle.title());
+        println!(
+            "  {:<6} {:>12} {:>12} {:>12} {:>12}",
+            "phase", "min", "median", "mean", "vs baseline"
+        );
+        for (phase, stats) in PHASES.iter().zip(stats) {
+            let stats = match stats {
+                Ok(stats) => stats,
+                Err(error) => {
+                    println!("  {phase:<6} failed: {error:#}");
+                    continue;
+                }
+            };
+            let compared = baseline
+                .and_then(|baseline| baseline.median(day, phase))
+                .map(|median| change(median, stats.median))
+                .unwrap_or_default();
+
+            println!(
+                "  {phase:<6} {:>12.2?} {:>12.2?} {:>12.2?} {compared:>12}",
+                stats.min, stats.median, stats.mean
+            );
+            medians.record(day, phase, stats.median);
+        }
+    }
+
+    Ok(medians)
+}
+
+/// Median times of an earlier run, saved by name to compare later runs against.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    medians: BTreeMap<(u8, String), Duration>,
+}
+
+impl Baseline {
+    /// File a baseline of the given name is kept in.
+    pub fn path(name: &str) -> PathBuf {
+        Path::new("target")
+            .join("aoc-bench")
+            .join(format!("{name}.txt"))
+    }
+
+    pub fn load(name: &str) -> Result<Baseline> {
+        Baseline::read(&Baseline::path(name)).with_context(|| format!("Loading baseline {name:?}"))
+    }
+
+    pub fn save(&self, name: &str) -> Result<()> {
+        self.write(&Baseline::path(name))
+            .with_context(|| format!("Saving baseline {name:?}"))
+    }
+
+    /// Reads a baseline from the `path`, with a `day phase nanoseconds` line for every median.
+    pub fn read(path: &Path) -> Result<Baseline> {
+        let text = fs::read_to_string(path).with_context(|| format!("Reading {path:?}"))?;
+
+        let mut medians = BTreeMap::new();
+        for (index, line) in text.lines().enumerate() {
+            let parse = || -> Option<((u8, String), Duration)> {
+                let mut fields = line.split_whitespace();
+                let day = fields.next()?.parse().ok()?;
+                let phase = fields.next()?.to_string();
+                let nanos = fields.next()?.parse().ok()?;
+                Some(((day, phase), Duration::from_nanos(nanos)))
+            };
+
+            let (key, median) = parse()
+                .with_context(|| format!("Malformed line {} in {path:?}: {line:?}", index + 1))?;
+            medians.insert(key, median);
+        }
+
+        Ok(Baseline { medians })
+    }
+
+    /// Writes the baseline to the `path`, creating the directories leading up to it.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("Creating {dir:?}"))?;
+        }
+
+        let mut text = String::new();
+        for ((day, phase), median) in &self.medians {
+            writeln!(text, "{day} {phase} {}", median.as_nanos())?;
+        }
+
+        fs::write(path, text).with_context(|| format!("Writing {path:?}"))
+    }
+
+    pub fn record(&mut self, day: u8, phase: &str, median: Duration) {
+        self.medians.insert((day, phase.to_string()), median);
+    }
+
+    pub fn median(&self, day: u8, phase: &str) -> Option<Duration> {
+        self.medians.get(&(day, phase.to_string())).copied()
+    }
+}
+
+/// Relative change from the `baseline` to the `current` time, like `+12.5%`.
+pub fn change(baseline: Duration, current: Duration) -> String {
+    let baseline = baseline.as_secs_f64();
+    if baseline == 0.0 {
+        return "n/a".to_string();
+    }
+
+    let change = (current.as_secs_f64() - baseline) / baseline * 100.0;
+    format!("{change:+.1}%")
+}
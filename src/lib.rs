@@ -0,0 +1,6 @@
+//! Runner for the solutions of all the days.
+
+pub mod bench;
+pub mod registry;
+pub mod scaffold;
+pub mod verify;
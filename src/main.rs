@@ -1,11 +1,139 @@
-use clap::{command, Command};
+use std::path::PathBuf;
 
-fn main() {
+use anyhow::{bail, Context, Result};
+use aoc::bench::{self, Baseline};
+use aoc::{registry, scaffold, verify};
+use aoc_common::{cli, default_filename, Input};
+use clap::builder::RangedU64ValueParser;
+use clap::{arg, command, value_parser, Command};
+
+fn main() -> Result<()> {
     let matches = command!()
-        .subcommand(Command::new("test").about("Just trying out some things"))
+        .subcommand_required(true)
+        .args(cli::verbosity_args())
+        .subcommand(Command::new("list").about("Lists the days that have been solved"))
+        .subcommand(
+            Command::new("run")
+                .about("Solves the puzzle of a single day")
+                .arg(arg!(<DAY> "Day of the puzzle").value_parser(value_parser!(u8)))
+                .arg(arg!(-i --input <FILE> "Puzzle input, or '-' for stdin [default: dayN.txt]")),
+        )
+        .subcommand(
+            Command::new("verify")
+                .about("Checks every day against the answers recorded for its real input")
+                .arg(
+                    arg!(-a --answers <FILE> "File with the recorded answers")
+                        .value_parser(value_parser!(PathBuf))
+                        .default_value("answers.txt"),
+                )
+                .arg(
+                    arg!(--inputs <DIR> "Directory with the dayN.txt inputs")
+                        .value_parser(value_parser!(PathBuf))
+                        .default_value("."),
+                ),
+        )
+        .subcommand(
+            Command::new("bench")
+                .about("Times the parsing and both parts of the puzzles separately")
+                .arg(arg!([DAY] "Day of the puzzle [default: every day]").value_parser(value_parser!(u8)))
+                .arg(
+                    arg!(-n --iterations <N> "Number of times to run each phase")
+                        .value_parser(RangedU64ValueParser::<usize>::new().range(1..))
+                        .default_value("10"),
+                )
+                .arg(
+                    arg!(--inputs <DIR> "Directory with the dayN.txt inputs")
+                        .value_parser(value_parser!(PathBuf))
+                        .default_value("."),
+                )
+                .arg(arg!(--"save-baseline" <NAME> "Saves the median times as a baseline of this name"))
+                .arg(arg!(--baseline <NAME> "Compares the median times against a saved baseline")),
+        )
+        .subcommand(
+            Command::new("new")
+                .about("Creates the crate of a new day from a template")
+                .arg(arg!(<DAY> "Day of the puzzle").value_parser(value_parser!(u8)))
+                .arg(arg!(-t --title <TITLE> "Title of the puzzle").default_value("Untitled"))
+                .arg(
+                    arg!(--root <DIR> "Workspace to create the day in [default: the one aoc was built from]")
+                        .value_parser(value_parser!(PathBuf)),
+                ),
+        )
         .get_matches();
 
-    if let Some(_test) = matches.subcommand_matches("test") {
-        println!("Testing it so!");
+    cli::init_logging(&matches);
+
+    match matches.subcommand() {
+        Some(("list", _)) => {
+            for puzzle in registry::puzzles() {
+                println!("Day {}: {}", puzzle.day(), puzzle.title());
+            }
+        }
+        Some(("run", matches)) => {
+            let day = *matches.get_one::<u8>("DAY").expect("DAY is required");
+            let puzzle =
+                registry::find(day).with_context(|| format!("Day {day} is not solved yet"))?;
+
+            let filename = matches
+                .get_one::<String>("input")
+                .cloned()
+                .unwrap_or_else(|| default_filename(day));
+
+            let parsed = puzzle.parse(Input::open(&filename)?)?;
+            aoc_common::report_answers(day, [parsed.part1(), parsed.part2()])?;
+        }
+        Some(("verify", matches)) => {
+            let answers = matches
+                .get_one::<PathBuf>("answers")
+                .expect("has a default");
+            let inputs = matches.get_one::<PathBuf>("inputs").expect("has a default");
+
+            let failures = verify::verify(answers, inputs)?;
+            if failures > 0 {
+                bail!("{failures} part(s) do not match the recorded answers");
+            }
+        }
+        Some(("bench", matches)) => {
+            let puzzles = match matches.get_one::<u8>("DAY") {
+                Some(&day) => {
+                    vec![registry::find(day)
+                        .with_context(|| format!("Day {day} is not solved yet"))?]
+                }
+                None => registry::puzzles().to_vec(),
+            };
+            let inputs = matches.get_one::<PathBuf>("inputs").expect("has a default");
+            let iterations = *matches
+                .get_one::<usize>("iterations")
+                .expect("has a default");
+
+            let baseline = match matches.get_one::<String>("baseline") {
+                Some(name) => Some(Baseline::load(name)?),
+                None => None,
+            };
+
+            let medians = bench::run(&puzzles, inputs, iterations, baseline.as_ref())?;
+            if let Some(name) = matches.get_one::<String>("save-baseline") {
+                medians.save(name)?;
+                println!(
+                    "Saved baseline {name:?} to {}",
+                    Baseline::path(name).display()
+                );
+            }
+        }
+        Some(("new", matches)) => {
+            let day = *matches.get_one::<u8>("DAY").expect("DAY is required");
+            let title = matches.get_one::<String>("title").expect("has a default");
+
+            let root = matches
+                .get_one::<PathBuf>("root")
+                .map_or(scaffold::workspace_root(), PathBuf::as_path);
+            for path in scaffold::new_day(root, day, title)? {
+                let path = path.strip_prefix(root).unwrap_or(&path);
+                println!("Wrote {}", path.display());
+            }
+        }
+        _ => unreachable!("subcommand is required"),
     }
+
+    Ok(())
 }
@@ -0,0 +1,21 @@
+//! All the days solved so far.
+
+use aoc_common::Puzzle;
+
+static PUZZLES: &[&dyn Puzzle] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+];
+
+/// Puzzles of every solved day, in the order of the advent calendar.
+pub fn puzzles() -> &'static [&'static dyn Puzzle] {
+    PUZZLES
+}
+
+/// Puzzle of the given day, if it has been solved.
+pub fn find(day: u8) -> Option<&'static dyn Puzzle> {
+    puzzles().iter().copied().find(|puzzle| puzzle.day() == day)
+}
@@ -0,0 +1,134 @@
+//! Generates the crate of a new day from the templates in `templates/day`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{ensure, Context, Result};
+
+const CARGO_TOML: &str = include_str!("../templates/day/Cargo.toml.template");
+const LIB_RS: &str = include_str!("../templates/day/src/lib.rs.template");
+const MAIN_RS: &str = include_str!("../templates/day/src/main.rs.template");
+const BACON_JOB: &str = include_str!("../templates/day/bacon.toml.template");
+const EXPECTED: &str = include_str!("../templates/day/expected.template");
+
+/// Root of the workspace the `aoc` binary was built from.
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Creates the crate of a new `day` in the workspace at `root`, with a test fixture stub
+/// and a bacon job, and registers it with the `aoc` runner.
+///
+/// Refuses to touch anything if the crate already exists. Returns the files written.
+pub fn new_day(root: &Path, day: u8, title: &str) -> Result<Vec<PathBuf>> {
+    ensure!(
+        (1..=25).contains(&day),
+        "There is no day {day} in the advent calendar"
+    );
+
+    let dir = root.join("crates").join(format!("day{day}"));
+    ensure!(
+        !dir.exists(),
+        "{} already exists, refusing to overwrite it",
+        dir.display()
+    );
+
+    let render = |template: &str| {
+        template
+            .replace("{{day}}", &day.to_string())
+            .replace("\"{{title}}\"", &format!("{title:?}"))
+            .replace("{{title}}", title)
+    };
+
+    // Work out all the edits before writing anything, so that a failure leaves no half-made day behind.
+    let cargo_toml = root.join("Cargo.toml");
+    let dependency = format!("day{day} = {{ path = \"crates/day{day}\" }}");
+    let registered_cargo_toml = insert_ordered(&read(&cargo_toml)?, &dependency, day, |line| {
+        line.strip_prefix("day")?
+            .split_once(" = { path")?
+            .0
+            .parse()
+            .ok()
+    })
+    .with_context(|| format!("Finding the day dependencies in {}", cargo_toml.display()))?;
+
+    let registry = root.join("src").join("registry.rs");
+    let entry = format!("    &day{day}::Day{day},");
+    let registered_registry = insert_ordered(&read(&registry)?, &entry, day, |line| {
+        line.trim()
+            .strip_prefix("&day")?
+            .split_once("::")?
+            .0
+            .parse()
+            .ok()
+    })
+    .with_context(|| format!("Finding the registered days in {}", registry.display()))?;
+
+    let bacon_toml = root.join("bacon.toml");
+    let mut bacon = read(&bacon_toml)?;
+    let job = render(BACON_JOB);
+    match bacon.find("[jobs.test]") {
+        Some(at) => bacon.insert_str(at, &job),
+        None => bacon.push_str(&format!("\n{job}")),
+    }
+
+    let mut written = vec![
+        write(&dir.join("Cargo.toml"), &render(CARGO_TOML))?,
+        write(&dir.join("src").join("lib.rs"), &render(LIB_RS))?,
+        write(&dir.join("src").join("main.rs"), &render(MAIN_RS))?,
+    ];
+
+    let fixture = root.join("test").join(format!("day{day}.txt"));
+    if !fixture.exists() {
+        written.push(write(&fixture, "")?);
+        written.push(write(
+            &fixture.with_extension("expected"),
+            &render(EXPECTED),
+        )?);
+    }
+
+    written.push(write(&cargo_toml, &registered_cargo_toml)?);
+    written.push(write(&registry, &registered_registry)?);
+    written.push(write(&bacon_toml, &bacon)?);
+
+    Ok(written)
+}
+
+/// Inserts `line` among the lines that `day_of` finds a day in, keeping them ordered by day.
+///
+/// Returns `None` when there are no such lines to insert it among.
+fn insert_ordered(
+    text: &str,
+    line: &str,
+    day: u8,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> Option<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| day_of(line).map(|day| (index, day)))
+        .collect();
+
+    let (last, _) = *days.last()?;
+    let at = days
+        .iter()
+        .find(|(_, other)| *other > day)
+        .map_or(last + 1, |(index, _)| *index);
+
+    lines.insert(at, line);
+    Some(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Reading {}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<PathBuf> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("Creating {}", dir.display()))?;
+    }
+
+    fs::write(path, contents).with_context(|| format!("Writing {}", path.display()))?;
+    Ok(path.to_path_buf())
+}
@@ -0,0 +1,110 @@
+//! Checks every registered day against the answers recorded for its real input.
+
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use aoc_common::{answer_matches, default_filename, AnswerSheet, Input, Puzzle};
+
+use crate::registry;
+
+/// Outcome of checking a single part of a puzzle.
+#[derive(Debug)]
+pub enum Verdict {
+    /// The answer matches the recorded one.
+    Pass(String),
+    /// The answer differs from the recorded one, or could not be computed.
+    Fail { expected: String, actual: String },
+    /// There is no recorded answer, or no input to compute one from.
+    Missing(String),
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass(answer) => write!(f, "pass ({answer})"),
+            Verdict::Fail { expected, actual } => {
+                write!(f, "FAIL (expected {expected}, got {actual})")
+            }
+            Verdict::Missing(reason) => write!(f, "missing ({reason})"),
+        }
+    }
+}
+
+/// Checks both parts of every registered day, reading the input of each day
+/// from `dayN.txt` in the `inputs` directory.
+///
+/// Prints a line per part and returns the number of failed parts.
+pub fn verify(answers: &Path, inputs: &Path) -> Result<usize> {
+    let sheet: AnswerSheet = fs::read_to_string(answers)
+        .with_context(|| format!("Reading answers from {answers:?}"))?
+        .parse()
+        .with_context(|| format!("Parsing answers in {answers:?}"))?;
+
+    let mut failures = 0;
+    for puzzle in registry::puzzles() {
+        let day = puzzle.day();
+        let [part1, part2] = verify_day(*puzzle, &sheet, inputs);
+
+        for (part, verdict) in [(1, part1), (2, part2)] {
+            if let Verdict::Fail { .. } = verdict {
+                failures += 1;
+            }
+            println!("Day {day}, part {part}: {verdict}");
+        }
+    }
+
+    Ok(failures)
+}
+
+fn verify_day(puzzle: &dyn Puzzle, sheet: &AnswerSheet, inputs: &Path) -> [Verdict; 2] {
+    let day = puzzle.day();
+    let expected = sheet.day(day);
+    let expected = [
+        expected.and_then(|expected| expected.part1.clone()),
+        expected.and_then(|expected| expected.part2.clone()),
+    ];
+
+    let path = inputs.join(default_filename(day));
+    if !path.exists() {
+        let reason = format!("no input at {}", path.display());
+        return [Verdict::Missing(reason.clone()), Verdict::Missing(reason)];
+    }
+
+    let parsed = path
+        .to_str()
+        .context("Input path is not UTF-8")
+        .and_then(Input::open)
+        .and_then(|input| puzzle.parse(input));
+
+    let [part1, part2] = match parsed {
+        Ok(parsed) => {
+            [parsed.part1(), parsed.part2()].map(|answer| answer.map_err(|err| format!("{err:#}")))
+        }
+        Err(err) => {
+            let message = format!("{err:#}");
+            [Err(message.clone()), Err(message)]
+        }
+    };
+
+    let [expected1, expected2] = expected;
+    [judge(expected1, part1), judge(expected2, part2)]
+}
+
+fn judge(expected: Option<String>, actual: Result<String, String>) -> Verdict {
+    let shown = match &actual {
+        Ok(answer) => answer.clone(),
+        Err(err) => format!("error: {err}"),
+    };
+
+    match expected {
+        Some(expected) if answer_matches(&expected, &actual) => Verdict::Pass(shown),
+        Some(expected) => Verdict::Fail {
+            expected,
+            actual: shown,
+        },
+        None if actual.is_ok() => Verdict::Missing(format!("no recorded answer, got {shown}")),
+        None => Verdict::Missing(format!("no recorded answer, {shown}")),
+    }
+}
@@ -0,0 +1,128 @@
+//! Checks the statistics, baselines and comparisons of `aoc bench`, and runs it on inputs
+//! written to a temporary directory.
+
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use aoc::bench::{change, Baseline, Stats};
+use aoc_common::test_support;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test");
+
+fn millis(millis: &[u64]) -> Vec<Duration> {
+    millis.iter().copied().map(Duration::from_millis).collect()
+}
+
+#[test]
+fn stats_summarize_the_samples_in_any_order() {
+    let stats = Stats::from_samples(millis(&[10, 2, 1, 3]));
+
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_millis(3));
+    assert_eq!(stats.mean, Duration::from_millis(4));
+
+    let stats = Stats::from_samples(millis(&[7]));
+    let seven = Duration::from_millis(7);
+    assert_eq!((stats.min, stats.median, stats.mean), (seven, seven, seven));
+}
+
+#[test]
+fn baselines_survive_a_round_trip() {
+    let path = test_support::temp_dir("bench-round-trip").join("aoc-bench/main.txt");
+
+    let mut baseline = Baseline::default();
+    baseline.record(1, "parse", Duration::from_nanos(1500));
+    baseline.record(5, "part2", Duration::from_millis(3));
+    baseline.write(&path).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "1 parse 1500\n5 part2 3000000\n"
+    );
+
+    let read = Baseline::read(&path).unwrap();
+    assert_eq!(read.median(1, "parse"), Some(Duration::from_nanos(1500)));
+    assert_eq!(read.median(5, "part2"), Some(Duration::from_millis(3)));
+    assert_eq!(read.median(5, "part1"), None);
+}
+
+#[test]
+fn malformed_baseline_lines_are_reported() {
+    let dir = test_support::temp_dir("bench-malformed");
+
+    for (name, text, number, line) in [
+        ("missing.txt", "1 parse 100\n2 part1\n", 2, "2 part1"),
+        ("day.txt", "day1 parse 100\n", 1, "day1 parse 100"),
+        ("nanos.txt", "1 parse 1.5ms\n", 1, "1 parse 1.5ms"),
+    ] {
+        let path = dir.join(name);
+        fs::write(&path, text).unwrap();
+
+        let error = Baseline::read(&path).unwrap_err().to_string();
+        assert_eq!(
+            error,
+            format!("Malformed line {number} in {path:?}: {line:?}")
+        );
+    }
+
+    let error = Baseline::read(&dir.join("absent.txt")).unwrap_err();
+    assert!(error.to_string().starts_with("Reading "), "{error}");
+}
+
+#[test]
+fn changes_are_relative_to_the_baseline() {
+    let ms = Duration::from_millis;
+
+    assert_eq!(change(ms(8), ms(9)), "+12.5%");
+    assert_eq!(change(ms(100), ms(50)), "-50.0%");
+    assert_eq!(change(ms(3), ms(3)), "+0.0%");
+    assert_eq!(change(Duration::ZERO, ms(3)), "n/a");
+}
+
+#[test]
+fn failing_phases_are_reported_and_the_other_puzzles_still_run() {
+    let inputs = test_support::temp_dir("bench-failures");
+    fs::copy(
+        Path::new(FIXTURES).join("day3.txt"),
+        inputs.join("day3.txt"),
+    )
+    .unwrap();
+    fs::write(inputs.join("day4.txt"), "not a card\n").unwrap();
+    fs::write(
+        inputs.join("day5.txt"),
+        "seeds: 1\n\nseed-to-location map:\n0 0 1\n",
+    )
+    .unwrap();
+
+    let output = test_support::run(
+        env!("CARGO_BIN_EXE_aoc"),
+        &["bench", "-n", "2", "--inputs", inputs.to_str().unwrap()],
+        None,
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{stdout}");
+    assert!(
+        stdout.contains("Day 3: Gear Ratios (2 iterations)"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("Day 4: failed to parse the input"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("part2  failed: Expected pairs of seed range start and length"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("  part1 "), "{stdout}");
+}
+
+#[test]
+fn zero_iterations_are_rejected() {
+    let output = test_support::run(env!("CARGO_BIN_EXE_aoc"), &["bench", "-n", "0"], None);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("invalid value '0'"), "{stderr}");
+}
@@ -0,0 +1,87 @@
+//! Runs every day against every puzzle example in `test/` and checks the answers
+//! against the `.expected` file next to the example.
+//!
+//! The day of an example is taken from its name: `day2part1.txt` belongs to day 2.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use aoc::registry;
+use aoc_common::{answer_matches, Expected, Input};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test");
+
+/// Day of the puzzle a fixture named like `day2part1.txt` belongs to.
+fn day_of(fixture: &Path) -> Option<u8> {
+    let name = fixture.file_name()?.to_str()?.strip_prefix("day")?;
+    let digits = name
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(name.len());
+    name[..digits].parse().ok()
+}
+
+fn fixtures() -> Vec<PathBuf> {
+    let mut fixtures: Vec<PathBuf> = fs::read_dir(FIXTURES)
+        .expect("reading the test directory")
+        .map(|entry| entry.expect("reading the test directory").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+
+    fixtures.sort();
+    fixtures
+}
+
+/// Checks a single fixture, returning the mismatches found.
+fn check(fixture: &Path) -> Result<Vec<String>> {
+    let day = day_of(fixture).context("Fixture name does not start with dayN")?;
+    let puzzle = registry::find(day).with_context(|| format!("Day {day} is not registered"))?;
+
+    let sidecar = fixture.with_extension("expected");
+    let expected: Expected = fs::read_to_string(&sidecar)
+        .with_context(|| format!("Reading {sidecar:?}"))?
+        .parse()
+        .with_context(|| format!("Parsing {sidecar:?}"))?;
+
+    let input = Input::open(fixture.to_str().context("Fixture path is not UTF-8")?)?;
+    let parsed = puzzle.parse(input)?;
+
+    let mut mismatches = Vec::new();
+    let parts = [
+        ("part1", expected.part1, parsed.part1()),
+        ("part2", expected.part2, parsed.part2()),
+    ];
+    for (part, expected, actual) in parts {
+        let Some(expected) = expected else {
+            continue;
+        };
+
+        let actual = actual.map_err(|err| format!("{err:#}"));
+        match actual {
+            _ if answer_matches(&expected, &actual) => {}
+            Ok(actual) => mismatches.push(format!("{part}: expected {expected}, got {actual}")),
+            Err(err) => mismatches.push(format!("{part}: expected {expected}, failed: {err}")),
+        }
+    }
+
+    Ok(mismatches)
+}
+
+#[test]
+fn every_fixture_gives_the_expected_answers() {
+    let fixtures = fixtures();
+    assert!(!fixtures.is_empty(), "No fixtures found in {FIXTURES}");
+
+    let mut failures = Vec::new();
+    for fixture in &fixtures {
+        let name = fixture.file_name().unwrap().to_string_lossy();
+        match check(fixture) {
+            Ok(mismatches) => {
+                failures.extend(mismatches.into_iter().map(|m| format!("{name}: {m}")));
+            }
+            Err(err) => failures.push(format!("{name}: {err:#}")),
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
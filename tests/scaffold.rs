@@ -0,0 +1,87 @@
+//! Runs `aoc new` in a temporary copy of the files of the workspace it edits.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Output;
+
+use aoc_common::test_support;
+
+const WORKSPACE: &str = env!("CARGO_MANIFEST_DIR");
+
+/// Copy of the files of the workspace that `aoc new` edits, in a directory of the given `name`.
+fn workspace(name: &str) -> PathBuf {
+    let root = test_support::temp_dir(name);
+    fs::create_dir(root.join("src")).unwrap();
+    for file in ["Cargo.toml", "bacon.toml", "src/registry.rs"] {
+        fs::copy(Path::new(WORKSPACE).join(file), root.join(file)).unwrap();
+    }
+
+    root
+}
+
+fn new_day(root: &Path, args: &[&str]) -> Output {
+    let mut args = [&["new"], args].concat();
+    args.extend(["--root", root.to_str().unwrap()]);
+
+    test_support::run(env!("CARGO_BIN_EXE_aoc"), &args, None)
+}
+
+fn read(root: &Path, file: &str) -> String {
+    fs::read_to_string(root.join(file)).unwrap_or_else(|err| panic!("reading {file}: {err}"))
+}
+
+#[test]
+fn creates_the_crate_fixture_and_bacon_job_of_a_new_day() {
+    let root = workspace("scaffold-new");
+    let output = new_day(&root, &["6", "--title", "Wait For It"]);
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Wrote crates/day6/src/lib.rs"), "{stdout}");
+
+    assert!(read(&root, "crates/day6/Cargo.toml").contains("name = \"day6\""));
+    assert!(read(&root, "crates/day6/src/lib.rs").contains("\"Wait For It\""));
+    assert!(root.join("crates/day6/src/main.rs").exists());
+    assert_eq!(read(&root, "test/day6.txt"), "");
+    assert!(root.join("test/day6.expected").exists());
+
+    assert!(read(&root, "bacon.toml").contains("[jobs.day6]"));
+    assert!(read(&root, "Cargo.toml").contains("day6 = { path = \"crates/day6\" }"));
+    assert!(read(&root, "src/registry.rs").contains("&day6::Day6,"));
+}
+
+#[test]
+fn refuses_to_overwrite_an_existing_day() {
+    let root = workspace("scaffold-existing");
+    assert!(new_day(&root, &["6"]).status.success());
+
+    let lib = root.join("crates/day6/src/lib.rs");
+    fs::write(&lib, "// solved already\n").unwrap();
+    let registry = read(&root, "src/registry.rs");
+
+    let output = new_day(&root, &["6"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("already exists, refusing to overwrite it"),
+        "{stderr}"
+    );
+
+    assert_eq!(fs::read_to_string(&lib).unwrap(), "// solved already\n");
+    assert_eq!(read(&root, "src/registry.rs"), registry);
+}
+
+#[test]
+fn rejects_a_day_outside_the_advent_calendar() {
+    let root = workspace("scaffold-day26");
+    let output = new_day(&root, &["26"]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("There is no day 26 in the advent calendar"));
+    assert!(!root.join("crates").exists());
+}
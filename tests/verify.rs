@@ -0,0 +1,94 @@
+//! Runs `aoc verify` on inputs and answers written to a temporary directory.
+
+use std::fs;
+use std::path::Path;
+use std::process::Output;
+
+use aoc_common::test_support;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test");
+
+/// Runs `aoc verify` with the `answers`, on the inputs of days 1 and 2 copied from the
+/// puzzle examples. The other days have no input.
+fn verify(name: &str, answers: &str) -> (Output, String) {
+    let dir = test_support::temp_dir(name);
+    let inputs = dir.join("inputs");
+    fs::create_dir(&inputs).unwrap();
+    for (fixture, input) in [("day1.txt", "day1.txt"), ("day2part1.txt", "day2.txt")] {
+        fs::copy(Path::new(FIXTURES).join(fixture), inputs.join(input)).unwrap();
+    }
+
+    let sheet = dir.join("answers.txt");
+    fs::write(&sheet, answers).unwrap();
+
+    let output = test_support::run(
+        env!("CARGO_BIN_EXE_aoc"),
+        &[
+            "verify",
+            "--answers",
+            sheet.to_str().unwrap(),
+            "--inputs",
+            inputs.to_str().unwrap(),
+        ],
+        None,
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+
+    (output, stdout)
+}
+
+#[test]
+fn reports_every_part_as_pass_fail_or_missing() {
+    let (output, stdout) = verify(
+        "verify-mismatch",
+        "[day1]\npart1 = 142\npart2 = 281\n\n[day2]\npart1 = 8\n",
+    );
+
+    assert!(!output.status.success(), "{stdout}");
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines[0], "Day 1, part 1: pass (142)");
+    assert_eq!(lines[1], "Day 1, part 2: FAIL (expected 281, got 142)");
+    assert_eq!(lines[2], "Day 2, part 1: pass (8)");
+    assert_eq!(
+        lines[3],
+        "Day 2, part 2: missing (no recorded answer, got 2286)"
+    );
+    assert!(
+        lines[4].starts_with("Day 3, part 1: missing (no input at "),
+        "{stdout}"
+    );
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("1 part(s) do not match the recorded answers"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn succeeds_when_no_part_fails() {
+    let (output, stdout) = verify(
+        "verify-match",
+        "[day1]\npart1 = 142\npart2 = 142\n\n[day2]\npart1 = 8\npart2 = 2286\n",
+    );
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(stdout.matches(": pass (").count(), 4, "{stdout}");
+    assert!(!stdout.contains("FAIL"), "{stdout}");
+}
+
+#[test]
+fn malformed_answers_are_reported() {
+    let (output, _) = verify("verify-malformed", "[day1]\npart1 142\n");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Expected 'part = answer' on line 2"),
+        "{stderr}"
+    );
+}